@@ -150,7 +150,8 @@ async function parseAgentAccount(data: Buffer): Promise<OnChainAgent | null> {
     return null;
   }
 
-  let offset = 8;
+  // Skip discriminator, plus the version byte on non-legacy (135-byte) layouts
+  let offset = data.length === 135 ? 8 : 9;
 
   // authority: Pubkey (32 bytes)
   const authority = new PublicKey(data.subarray(offset, offset + 32)).toBase58();
@@ -166,6 +167,9 @@ async function parseAgentAccount(data: Buffer): Promise<OnChainAgent | null> {
   const totalCommitments = Number(data.readBigUInt64LE(offset));
   offset += 8;
 
+  // total_verified: u64, accountability_score: u16
+  offset += 8 + 2;
+
   // created_at: i64
   const createdAt = Number(data.readBigInt64LE(offset));
 
@@ -111,7 +111,8 @@ function deserializeAgent(
   data: Buffer,
   network?: Network
 ): AgentProfile {
-  let offset = 8; // skip discriminator
+  // Skip discriminator, plus the version byte on non-legacy (135-byte) layouts
+  let offset = data.length === 135 ? 8 : 9;
 
   const authority = new PublicKey(data.subarray(offset, offset + 32)).toBase58();
   offset += 32;
@@ -34,6 +34,8 @@ const SOLPRISM_PROGRAM_ID = new PublicKey(
 
 const SEED_AGENT = Buffer.from("agent");
 const SEED_COMMITMENT = Buffer.from("commitment");
+const SEED_CONFIG = Buffer.from("config");
+const SEED_TRUST_HISTORY = Buffer.from("trust_history");
 
 /** Anchor 8-byte instruction discriminators */
 const DISCRIMINATORS = {
@@ -252,12 +254,19 @@ export function buildRegisterAgentIx(
   name: string
 ): TransactionInstruction {
   const [agentProfile] = deriveAgentPDA(authority);
+  const [trustHistory] = PublicKey.findProgramAddressSync(
+    [SEED_TRUST_HISTORY, agentProfile.toBuffer()],
+    programId
+  );
+  const [config] = PublicKey.findProgramAddressSync([SEED_CONFIG], programId);
   const data = Buffer.concat([DISCRIMINATORS.registerAgent, encodeString(name)]);
 
   return new TransactionInstruction({
     keys: [
       { pubkey: agentProfile, isSigner: false, isWritable: true },
+      { pubkey: trustHistory, isSigner: false, isWritable: true },
       { pubkey: authority, isSigner: true, isWritable: true },
+      { pubkey: config, isSigner: false, isWritable: false },
       { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
     ],
     programId,
@@ -329,7 +338,8 @@ export function deserializeAgentProfile(
     return null;
   }
 
-  let offset = 8;
+  // Legacy (135-byte) profiles predate the leading version byte
+  let offset = data.length === 135 ? 8 : 9;
   const authority = new PublicKey(data.slice(offset, offset + 32)).toBase58();
   offset += 32;
 
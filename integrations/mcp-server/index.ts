@@ -67,6 +67,22 @@ function deriveAgentPda(authority: PublicKey): PublicKey {
   return pda;
 }
 
+function deriveConfigPda(): PublicKey {
+  const [pda] = PublicKey.findProgramAddressSync(
+    [Buffer.from("config")],
+    PROGRAM_ID
+  );
+  return pda;
+}
+
+function deriveTrustHistoryPda(agentPda: PublicKey): PublicKey {
+  const [pda] = PublicKey.findProgramAddressSync(
+    [Buffer.from("trust_history"), agentPda.toBuffer()],
+    PROGRAM_ID
+  );
+  return pda;
+}
+
 function deriveCommitmentPda(
   authority: PublicKey,
   commitId: string
@@ -234,7 +250,13 @@ server.setRequestHandler(CallToolRequestSchema, async (request) => {
         const ix = new TransactionInstruction({
           keys: [
             { pubkey: agentPda, isSigner: false, isWritable: true },
+            {
+              pubkey: deriveTrustHistoryPda(agentPda),
+              isSigner: false,
+              isWritable: true,
+            },
             { pubkey: wallet.publicKey, isSigner: true, isWritable: true },
+            { pubkey: deriveConfigPda(), isSigner: false, isWritable: false },
             {
               pubkey: SystemProgram.programId,
               isSigner: false,
@@ -417,12 +439,16 @@ server.setRequestHandler(CallToolRequestSchema, async (request) => {
           };
         }
 
-        // Parse agent account data (Anchor format: 8-byte discriminator + fields)
+        // Parse agent account data (Anchor format: 8-byte discriminator,
+        // a version byte on non-legacy (135-byte) layouts, then fields)
         const data = account.data;
-        const authorityBytes = data.subarray(8, 40);
-        const nameLen = data.readUInt32LE(40);
-        const agentName = data.subarray(44, 44 + nameLen).toString("utf-8");
-        const commitCount = data.readUInt32LE(44 + nameLen);
+        const base = data.length === 135 ? 8 : 9;
+        const authorityBytes = data.subarray(base, base + 32);
+        const nameLen = data.readUInt32LE(base + 32);
+        const agentName = data
+          .subarray(base + 36, base + 36 + nameLen)
+          .toString("utf-8");
+        const commitCount = data.readUInt32LE(base + 36 + nameLen);
 
         return {
           content: [
@@ -50,6 +50,8 @@ const AGENT_PROFILE_DISCRIMINATOR = Buffer.from([
 
 const SEED_AGENT = Buffer.from("agent");
 const SEED_COMMITMENT = Buffer.from("commitment");
+const SEED_CONFIG = Buffer.from("config");
+const SEED_TRUST_HISTORY = Buffer.from("trust_history");
 
 // ─── Types ──────────────────────────────────────────────────────────────────
 
@@ -175,10 +177,17 @@ function buildRegisterAgentIx(
   programId: PublicKey,
 ): TransactionInstruction {
   const [agentProfile] = deriveAgentPDA(authority, programId);
+  const [trustHistory] = PublicKey.findProgramAddressSync(
+    [SEED_TRUST_HISTORY, agentProfile.toBuffer()],
+    programId,
+  );
+  const [config] = PublicKey.findProgramAddressSync([SEED_CONFIG], programId);
   return new TransactionInstruction({
     keys: [
       { pubkey: agentProfile, isSigner: false, isWritable: true },
+      { pubkey: trustHistory, isSigner: false, isWritable: true },
       { pubkey: authority, isSigner: true, isWritable: true },
+      { pubkey: config, isSigner: false, isWritable: false },
       { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
     ],
     programId,
@@ -0,0 +1,144 @@
+//! Canonical proof hashing for structured reasoning.
+//!
+//! The `proof_hash` stored by `store_reasoning_proof` is opaque to the
+//! program, so every client must hash reasoning the same way for proofs to
+//! be independently checkable. This module defines that one way.
+//!
+//! The hash is `sha256(borsh(ReasoningInput))`. Borsh encodes fields in
+//! declaration order, little-endian and without padding:
+//!
+//! | field        | encoding                                              |
+//! |--------------|-------------------------------------------------------|
+//! | `domain`     | `u8` (`ProofDomain` discriminant)                     |
+//! | `action`     | `u32` byte length, then UTF-8 bytes                   |
+//! | `rationale`  | `u32` byte length, then UTF-8 bytes                   |
+//! | `confidence` | `u8` (0-100)                                          |
+//! | `timestamp`  | `i64`                                                 |
+//! | `metadata`   | `u32` entry count, then each key and value as strings |
+//!
+//! `metadata` is a `BTreeMap`, so entries are always written in ascending
+//! byte-wise key order regardless of how they were inserted.
+
+use std::collections::BTreeMap;
+
+use anchor_lang::prelude::borsh::{self, BorshDeserialize, BorshSerialize};
+
+use crate::ProofDomain;
+
+/// Structured reasoning payload committed to by a proof hash.
+///
+/// Never an instruction argument or account, so it derives plain borsh
+/// rather than the Anchor wrappers (which would need IDL support for
+/// `BTreeMap`).
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct ReasoningInput {
+    /// Domain the reasoning applies to, as `ProofDomain::as_u8`
+    pub domain: u8,
+    /// Action the agent decided on
+    pub action: String,
+    /// Free-form explanation of the decision
+    pub rationale: String,
+    /// Self-reported confidence (0-100)
+    pub confidence: u8,
+    /// Unix timestamp the reasoning was produced at
+    pub timestamp: i64,
+    /// Extra key/value context, canonically ordered by key
+    pub metadata: BTreeMap<String, String>,
+}
+
+impl ReasoningInput {
+    pub fn new(
+        domain: ProofDomain,
+        action: impl Into<String>,
+        rationale: impl Into<String>,
+        confidence: u8,
+        timestamp: i64,
+    ) -> Self {
+        Self {
+            domain: domain.as_u8(),
+            action: action.into(),
+            rationale: rationale.into(),
+            confidence,
+            timestamp,
+            metadata: BTreeMap::new(),
+        }
+    }
+}
+
+/// Canonical proof hash of `input`: sha256 over its borsh encoding.
+///
+/// ```
+/// use axiom::hash::{hash_reasoning, ReasoningInput};
+/// use axiom::ProofDomain;
+///
+/// let mut a = ReasoningInput::new(ProofDomain::Defi, "swap", "spread > 2%", 80, 1_700_000_000);
+/// let mut b = a.clone();
+/// a.metadata.insert("pool".into(), "SOL/USDC".into());
+/// a.metadata.insert("venue".into(), "jupiter".into());
+/// b.metadata.insert("venue".into(), "jupiter".into());
+/// b.metadata.insert("pool".into(), "SOL/USDC".into());
+///
+/// assert_eq!(hash_reasoning(&a), hash_reasoning(&b));
+/// ```
+pub fn hash_reasoning(input: &ReasoningInput) -> [u8; 32] {
+    let encoded = input
+        .try_to_vec()
+        .expect("borsh serialization into a Vec cannot fail");
+    solana_sha256_hasher::hash(&encoded).to_bytes()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample() -> ReasoningInput {
+        let mut input =
+            ReasoningInput::new(ProofDomain::Defi, "swap", "spread > 2%", 80, 1_700_000_000);
+        input.metadata.insert("pool".into(), "SOL/USDC".into());
+        input
+    }
+
+    fn hex(bytes: &[u8]) -> String {
+        bytes.iter().map(|b| format!("{b:02x}")).collect()
+    }
+
+    #[test]
+    fn encoding_and_digest_match_the_documented_layout() {
+        // Field by field, as laid out in the module docs
+        let expected = concat!(
+            "00",                             // domain: Defi
+            "0400000073776170",               // action: "swap"
+            "0b000000737072656164203e203225", // rationale: "spread > 2%"
+            "50",                             // confidence: 80
+            "00f1536500000000",               // timestamp: 1_700_000_000
+            "01000000",                       // metadata: one entry
+            "04000000706f6f6c",               // key: "pool"
+            "08000000534f4c2f55534443",       // value: "SOL/USDC"
+        );
+        assert_eq!(hex(&sample().try_to_vec().unwrap()), expected);
+        assert_eq!(
+            hex(&hash_reasoning(&sample())),
+            "410a866c030ce9abc970aa06318f2db865cfffc741dd3f8d45d407e1483d671e"
+        );
+    }
+
+    #[test]
+    fn every_field_changes_the_hash() {
+        let base = hash_reasoning(&sample());
+        let variants: [fn(&mut ReasoningInput); 6] = [
+            |i| i.domain = ProofDomain::Governance.as_u8(),
+            |i| i.action.push('!'),
+            |i| i.rationale.push('!'),
+            |i| i.confidence -= 1,
+            |i| i.timestamp += 1,
+            |i| {
+                i.metadata.insert("venue".into(), "jupiter".into());
+            },
+        ];
+        for change in variants {
+            let mut input = sample();
+            change(&mut input);
+            assert_ne!(hash_reasoning(&input), base, "{input:?}");
+        }
+    }
+}
@@ -0,0 +1,1406 @@
+//! Off-chain instruction builders (enabled with the `client` feature).
+//!
+//! Each builder derives the PDAs from the same seed constants the program
+//! validates against, and encodes data with the Anchor-generated
+//! discriminators, so transactions can be assembled without an IDL.
+//!
+//! `agent_authority` parameters name the wallet an agent registered with,
+//! which seeds its profile PDA and stays fixed across ownership transfers.
+//! `authority` parameters are the profile's current owner, who signs.
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::solana_program::{bpf_loader_upgradeable, system_program};
+use anchor_lang::{InstructionData, ToAccountMetas};
+
+use crate::{
+    accounts, instruction, Attestation, ProofDomain, ProofInput, TrustLevel, AGENT_SEED,
+    COMMITMENT_SEED, CONFIG_SEED, ID, PROOF_SEED, STAKE_SEED, TREASURY_SEED, TRUST_HISTORY_SEED,
+};
+
+// ─── PDA Derivation ────────────────────────────────────────────────────────
+
+/// Agent profile PDA for the wallet an agent registered with.
+pub fn agent_profile_address(authority: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[AGENT_SEED, authority.as_ref()], &ID).0
+}
+
+/// Reasoning commitment PDA for an agent profile and nonce.
+pub fn commitment_address(agent_profile: &Pubkey, nonce: u64) -> Pubkey {
+    Pubkey::find_program_address(
+        &[
+            COMMITMENT_SEED,
+            agent_profile.as_ref(),
+            &nonce.to_le_bytes(),
+        ],
+        &ID,
+    )
+    .0
+}
+
+/// Reasoning proof PDA for an agent profile and proof hash.
+pub fn proof_address(agent_profile: &Pubkey, proof_hash: &[u8; 32]) -> Pubkey {
+    Pubkey::find_program_address(&[PROOF_SEED, agent_profile.as_ref(), proof_hash], &ID).0
+}
+
+/// Singleton program config PDA.
+pub fn config_address() -> Pubkey {
+    Pubkey::find_program_address(&[CONFIG_SEED], &ID).0
+}
+
+/// Stake account PDA backing a proof.
+pub fn stake_address(proof: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[STAKE_SEED, proof.as_ref()], &ID).0
+}
+
+/// Singleton treasury PDA.
+pub fn treasury_address() -> Pubkey {
+    Pubkey::find_program_address(&[TREASURY_SEED], &ID).0
+}
+
+/// Trust history PDA for an agent profile.
+pub fn trust_history_address(agent_profile: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[TRUST_HISTORY_SEED, agent_profile.as_ref()], &ID).0
+}
+
+/// The program's ProgramData account (holds the upgrade authority).
+pub fn program_data_address() -> Pubkey {
+    Pubkey::find_program_address(&[ID.as_ref()], &bpf_loader_upgradeable::ID).0
+}
+
+fn build(accounts: impl ToAccountMetas, data: impl InstructionData) -> Instruction {
+    Instruction {
+        program_id: ID,
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+// ─── Agents ────────────────────────────────────────────────────────────────
+
+/// `register_agent`, paid and signed by `authority`.
+pub fn register_agent_ix(authority: Pubkey, name: String) -> Instruction {
+    let agent_profile = agent_profile_address(&authority);
+    build(
+        accounts::RegisterAgent {
+            trust_history: trust_history_address(&agent_profile),
+            agent_profile,
+            authority,
+            config: config_address(),
+            system_program: system_program::ID,
+        },
+        instruction::RegisterAgent { name },
+    )
+}
+
+/// `register_agent_with_attestation`, paid by `authority` and co-signed by
+/// the config oracle.
+pub fn register_agent_with_attestation_ix(
+    authority: Pubkey,
+    oracle: Pubkey,
+    name: String,
+    attestation: Attestation,
+) -> Instruction {
+    let agent_profile = agent_profile_address(&authority);
+    build(
+        accounts::RegisterAgentWithAttestation {
+            trust_history: trust_history_address(&agent_profile),
+            agent_profile,
+            authority,
+            config: config_address(),
+            oracle,
+            system_program: system_program::ID,
+        },
+        instruction::RegisterAgentWithAttestation { name, attestation },
+    )
+}
+
+/// `migrate_agent_profile` for `authority`'s legacy profile.
+pub fn migrate_agent_profile_ix(authority: Pubkey) -> Instruction {
+    build(
+        accounts::MigrateAgentProfile {
+            agent_profile: agent_profile_address(&authority),
+            authority,
+            system_program: system_program::ID,
+        },
+        instruction::MigrateAgentProfile {},
+    )
+}
+
+/// `set_delegate`, signed by the agent's `authority`.
+pub fn set_delegate_ix(
+    authority: Pubkey,
+    agent_authority: &Pubkey,
+    delegate: Pubkey,
+) -> Instruction {
+    build(
+        accounts::UpdateAgent {
+            agent_profile: agent_profile_address(agent_authority),
+            authority,
+            config: config_address(),
+        },
+        instruction::SetDelegate { delegate },
+    )
+}
+
+/// `revoke_delegate`, signed by the agent's `authority`.
+pub fn revoke_delegate_ix(authority: Pubkey, agent_authority: &Pubkey) -> Instruction {
+    build(
+        accounts::UpdateAgent {
+            agent_profile: agent_profile_address(agent_authority),
+            authority,
+            config: config_address(),
+        },
+        instruction::RevokeDelegate {},
+    )
+}
+
+/// `transfer_agent_ownership`, signed by the agent's current `authority`.
+pub fn transfer_agent_ownership_ix(
+    authority: Pubkey,
+    agent_authority: &Pubkey,
+    new_owner: Pubkey,
+) -> Instruction {
+    build(
+        accounts::UpdateAgent {
+            agent_profile: agent_profile_address(agent_authority),
+            authority,
+            config: config_address(),
+        },
+        instruction::TransferAgentOwnership { new_owner },
+    )
+}
+
+// ─── Reasoning Proofs ──────────────────────────────────────────────────────
+
+/// `store_reasoning_proof` for the agent registered by `agent_authority`.
+///
+/// `submitter` signs and pays; it must be the authority or its delegate.
+pub fn store_reasoning_proof_ix(
+    submitter: Pubkey,
+    agent_authority: &Pubkey,
+    proof_hash: [u8; 32],
+    domain: ProofDomain,
+    ttl_seconds: i64,
+    nonce: u64,
+) -> Instruction {
+    let agent_profile = agent_profile_address(agent_authority);
+    build(
+        accounts::StoreReasoningProof {
+            proof: proof_address(&agent_profile, &proof_hash),
+            agent_profile,
+            submitter,
+            config: config_address(),
+            system_program: system_program::ID,
+        },
+        instruction::StoreReasoningProof {
+            proof_hash,
+            domain: domain.as_u8(),
+            ttl_seconds,
+            nonce,
+        },
+    )
+}
+
+/// `store_reasoning_proofs`, appending each proof PDA as a remaining account.
+pub fn store_reasoning_proofs_ix(
+    submitter: Pubkey,
+    agent_authority: &Pubkey,
+    proofs: Vec<ProofInput>,
+    ttl_seconds: i64,
+    nonce: u64,
+) -> Instruction {
+    let agent_profile = agent_profile_address(agent_authority);
+    let mut ix = build(
+        accounts::StoreReasoningProofs {
+            agent_profile,
+            submitter,
+            config: config_address(),
+            system_program: system_program::ID,
+        },
+        instruction::StoreReasoningProofs {
+            proofs: proofs.clone(),
+            ttl_seconds,
+            nonce,
+        },
+    );
+    ix.accounts.extend(
+        proofs
+            .iter()
+            .map(|p| AccountMeta::new(proof_address(&agent_profile, &p.proof_hash), false)),
+    );
+    ix
+}
+
+/// `verify_proof` (permissionless) for a proof of the agent registered by
+/// `agent_authority`.
+pub fn verify_proof_ix(agent_authority: &Pubkey, proof: Pubkey) -> Instruction {
+    build(
+        accounts::VerifyProof {
+            proof,
+            agent_profile: agent_profile_address(agent_authority),
+            config: config_address(),
+        },
+        instruction::VerifyProof {},
+    )
+}
+
+/// `challenge_proof`, signed by the config oracle.
+pub fn challenge_proof_ix(oracle: Pubkey, proof: Pubkey) -> Instruction {
+    build(
+        accounts::ChallengeProof {
+            config: config_address(),
+            proof,
+            oracle,
+        },
+        instruction::ChallengeProof {},
+    )
+}
+
+/// `close_proof`, sending the reclaimed rent to `recipient`.
+pub fn close_proof_ix(
+    authority: Pubkey,
+    agent_authority: &Pubkey,
+    proof: Pubkey,
+    recipient: Pubkey,
+) -> Instruction {
+    build(
+        accounts::CloseProof {
+            proof,
+            agent_profile: agent_profile_address(agent_authority),
+            authority,
+            recipient,
+        },
+        instruction::CloseProof {},
+    )
+}
+
+// ─── Stakes ────────────────────────────────────────────────────────────────
+
+/// `stake_on_proof`, moving `amount` lamports from `authority`.
+pub fn stake_on_proof_ix(
+    authority: Pubkey,
+    agent_authority: &Pubkey,
+    proof: Pubkey,
+    amount: u64,
+) -> Instruction {
+    build(
+        accounts::StakeOnProof {
+            stake: stake_address(&proof),
+            proof,
+            agent_profile: agent_profile_address(agent_authority),
+            authority,
+            config: config_address(),
+            system_program: system_program::ID,
+        },
+        instruction::StakeOnProof { amount },
+    )
+}
+
+/// `unstake`, returning the stake to `authority`.
+pub fn unstake_ix(authority: Pubkey, agent_authority: &Pubkey, proof: Pubkey) -> Instruction {
+    build(
+        accounts::Unstake {
+            stake: stake_address(&proof),
+            proof,
+            agent_profile: agent_profile_address(agent_authority),
+            authority,
+            config: config_address(),
+        },
+        instruction::Unstake {},
+    )
+}
+
+/// `slash`, signed by the config oracle.
+pub fn slash_ix(oracle: Pubkey, agent_authority: &Pubkey, proof: Pubkey, bps: u16) -> Instruction {
+    build(
+        accounts::Slash {
+            config: config_address(),
+            stake: stake_address(&proof),
+            proof,
+            agent_profile: agent_profile_address(agent_authority),
+            treasury: treasury_address(),
+            oracle,
+        },
+        instruction::Slash { bps },
+    )
+}
+
+/// `initialize_treasury`, signed and paid by the config admin.
+pub fn initialize_treasury_ix(admin: Pubkey) -> Instruction {
+    build(
+        accounts::InitializeTreasury {
+            treasury: treasury_address(),
+            config: config_address(),
+            admin,
+            system_program: system_program::ID,
+        },
+        instruction::InitializeTreasury {},
+    )
+}
+
+/// `withdraw_treasury`, signed by the config admin.
+pub fn withdraw_treasury_ix(admin: Pubkey, recipient: Pubkey, amount: u64) -> Instruction {
+    build(
+        accounts::WithdrawTreasury {
+            treasury: treasury_address(),
+            config: config_address(),
+            admin,
+            recipient,
+        },
+        instruction::WithdrawTreasury { amount },
+    )
+}
+
+// ─── Config & Trust ────────────────────────────────────────────────────────
+
+/// `initialize_config`; `admin` must be the program's upgrade authority,
+/// and signs, pays and becomes the config admin.
+pub fn initialize_config_ix(admin: Pubkey, oracle: Pubkey) -> Instruction {
+    build(
+        accounts::InitializeConfig {
+            config: config_address(),
+            admin,
+            program: ID,
+            program_data: program_data_address(),
+            system_program: system_program::ID,
+        },
+        instruction::InitializeConfig { oracle },
+    )
+}
+
+/// `set_oracle`, signed by the config admin.
+pub fn set_oracle_ix(admin: Pubkey, new_oracle: Pubkey) -> Instruction {
+    build(
+        accounts::UpdateConfig {
+            config: config_address(),
+            admin,
+        },
+        instruction::SetOracle { new_oracle },
+    )
+}
+
+/// `set_paused`, signed by the config admin.
+pub fn set_paused_ix(admin: Pubkey, paused: bool) -> Instruction {
+    build(
+        accounts::UpdateConfig {
+            config: config_address(),
+            admin,
+        },
+        instruction::SetPaused { paused },
+    )
+}
+
+/// `set_min_trust_bps`, signed by the config admin.
+pub fn set_min_trust_bps_ix(admin: Pubkey, min_trust_bps: u16) -> Instruction {
+    build(
+        accounts::UpdateConfig {
+            config: config_address(),
+            admin,
+        },
+        instruction::SetMinTrustBps { min_trust_bps },
+    )
+}
+
+/// `set_challenge_window`, signed by the config admin.
+pub fn set_challenge_window_ix(admin: Pubkey, challenge_window_secs: i64) -> Instruction {
+    build(
+        accounts::UpdateConfig {
+            config: config_address(),
+            admin,
+        },
+        instruction::SetChallengeWindow {
+            challenge_window_secs,
+        },
+    )
+}
+
+/// `set_min_stake_for_high`, signed by the config admin.
+pub fn set_min_stake_for_high_ix(
+    admin: Pubkey,
+    min_stake_for_high: u64,
+    min_stake_lock_secs: i64,
+) -> Instruction {
+    build(
+        accounts::UpdateConfig {
+            config: config_address(),
+            admin,
+        },
+        instruction::SetMinStakeForHigh {
+            min_stake_for_high,
+            min_stake_lock_secs,
+        },
+    )
+}
+
+/// `set_min_domain_bps`, signed by the config admin.
+pub fn set_min_domain_bps_ix(
+    admin: Pubkey,
+    min_defi_bps: u16,
+    min_prediction_bps: u16,
+) -> Instruction {
+    build(
+        accounts::UpdateConfig {
+            config: config_address(),
+            admin,
+        },
+        instruction::SetMinDomainBps {
+            min_defi_bps,
+            min_prediction_bps,
+        },
+    )
+}
+
+/// `initialize_trust_history` for the agent registered by `agent_authority`.
+pub fn initialize_trust_history_ix(payer: Pubkey, agent_authority: &Pubkey) -> Instruction {
+    let agent_profile = agent_profile_address(agent_authority);
+    build(
+        accounts::InitializeTrustHistory {
+            trust_history: trust_history_address(&agent_profile),
+            agent_profile,
+            payer,
+            system_program: system_program::ID,
+        },
+        instruction::InitializeTrustHistory {},
+    )
+}
+
+/// `attest_conviction` for the agent registered by `agent_authority`.
+///
+/// Agents registered before registration created the trust history need
+/// an `initialize_trust_history_ix` first.
+pub fn attest_conviction_ix(
+    oracle: Pubkey,
+    agent_authority: &Pubkey,
+    score_bps: u16,
+    defi_bps: u16,
+    prediction_bps: u16,
+    trust_level: TrustLevel,
+    attested_at: i64,
+) -> Instruction {
+    let agent_profile = agent_profile_address(agent_authority);
+    build(
+        accounts::AttestConviction {
+            config: config_address(),
+            agent_profile,
+            trust_history: trust_history_address(&agent_profile),
+            oracle,
+        },
+        instruction::AttestConviction {
+            score_bps,
+            defi_bps,
+            prediction_bps,
+            trust_level: trust_level.as_u8(),
+            attested_at,
+        },
+    )
+}
+
+/// `attest_conviction_batch`, signed by the config oracle. Each entry
+/// pairs an agent's registration wallet with its attestation.
+pub fn attest_conviction_batch_ix(
+    oracle: Pubkey,
+    entries: Vec<(Pubkey, Attestation)>,
+) -> Instruction {
+    let mut ix = build(
+        accounts::AttestConvictionBatch {
+            config: config_address(),
+            oracle,
+        },
+        instruction::AttestConvictionBatch {
+            attestations: entries.iter().map(|(_, a)| *a).collect(),
+        },
+    );
+    for (agent_authority, _) in &entries {
+        let agent_profile = agent_profile_address(agent_authority);
+        ix.accounts.push(AccountMeta::new(agent_profile, false));
+        ix.accounts.push(AccountMeta::new(
+            trust_history_address(&agent_profile),
+            false,
+        ));
+    }
+    ix
+}
+
+/// `verify_trust` for the agent registered by `agent_authority`.
+pub fn verify_trust_ix(agent_authority: &Pubkey, required: TrustLevel) -> Instruction {
+    build(
+        accounts::VerifyTrust {
+            agent_profile: agent_profile_address(agent_authority),
+        },
+        instruction::VerifyTrust {
+            required: required.as_u8(),
+        },
+    )
+}
+
+// ─── Compute Budget ────────────────────────────────────────────────────────
+
+/// Native compute budget program.
+pub const COMPUTE_BUDGET_PROGRAM_ID: Pubkey =
+    pubkey!("ComputeBudget111111111111111111111111111111");
+
+/// Optional compute budget settings for a transaction. The default sets
+/// neither, leaving the cluster defaults in place.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ComputeBudget {
+    /// Compute unit limit (`SetComputeUnitLimit`)
+    pub unit_limit: Option<u32>,
+    /// Priority fee per compute unit (`SetComputeUnitPrice`)
+    pub priority_fee_microlamports: Option<u64>,
+}
+
+impl ComputeBudget {
+    /// The compute budget instructions for these settings, in the order
+    /// they should lead a transaction.
+    pub fn instructions(&self) -> Vec<Instruction> {
+        // Borsh-encoded `ComputeBudgetInstruction` variants 2 and 3
+        let mut ixs = Vec::new();
+        if let Some(units) = self.unit_limit {
+            ixs.push(compute_budget_ix(2, &units.to_le_bytes()));
+        }
+        if let Some(price) = self.priority_fee_microlamports {
+            ixs.push(compute_budget_ix(3, &price.to_le_bytes()));
+        }
+        ixs
+    }
+}
+
+fn compute_budget_ix(tag: u8, arg: &[u8]) -> Instruction {
+    let mut data = Vec::with_capacity(1 + arg.len());
+    data.push(tag);
+    data.extend_from_slice(arg);
+    Instruction {
+        program_id: COMPUTE_BUDGET_PROGRAM_ID,
+        accounts: Vec::new(),
+        data,
+    }
+}
+
+/// `ix` preceded by the compute budget instructions for `budget`, e.g. to
+/// attach a priority fee to an `attest_conviction_ix` during congestion.
+pub fn with_compute_budget(budget: ComputeBudget, ix: Instruction) -> Vec<Instruction> {
+    let mut ixs = budget.instructions();
+    ixs.push(ix);
+    ixs
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+    use std::collections::BTreeMap;
+    use std::sync::Once;
+
+    use anchor_lang::solana_program::entrypoint::{
+        deserialize, ProgramResult, BPF_ALIGN_OF_U128, MAX_PERMITTED_DATA_INCREASE, NON_DUP_MARKER,
+        SUCCESS,
+    };
+    use solana_sysvar::program_stubs::{set_syscall_stubs, SyscallStubs};
+
+    use super::*;
+    use crate::tests::zeroed;
+    use crate::{
+        AgentProfile, AxiomError, Config, ReasoningProof, StakeAccount, Treasury, TrustHistory,
+        AGENT_PROFILE_VERSION,
+    };
+
+    thread_local! {
+        static NOW: Cell<i64> = const { Cell::new(0) };
+    }
+
+    /// Serves the `Clock` (at the calling thread's `NOW`) and `Rent`
+    /// sysvars to handlers run off-chain.
+    struct Sysvars;
+
+    impl SyscallStubs for Sysvars {
+        fn sol_get_clock_sysvar(&self, var_addr: *mut u8) -> u64 {
+            let clock = Clock {
+                unix_timestamp: NOW.with(Cell::get),
+                ..Clock::default()
+            };
+            unsafe { var_addr.cast::<Clock>().write(clock) };
+            SUCCESS
+        }
+
+        fn sol_get_rent_sysvar(&self, var_addr: *mut u8) -> u64 {
+            unsafe { var_addr.cast::<Rent>().write(Rent::default()) };
+            SUCCESS
+        }
+    }
+
+    struct TestAccount {
+        lamports: u64,
+        data: Vec<u8>,
+        owner: Pubkey,
+    }
+
+    impl TestAccount {
+        /// Rent-exempt program account holding `value`, sized for its
+        /// largest encoding.
+        fn program<T: AccountSerialize + Space>(value: &T) -> Self {
+            let mut data = Vec::new();
+            value.try_serialize(&mut data).unwrap();
+            data.resize(8 + T::INIT_SPACE, 0);
+            Self {
+                lamports: Rent::default().minimum_balance(data.len()),
+                data,
+                owner: ID,
+            }
+        }
+
+        /// An address nothing has been created at yet.
+        fn empty() -> Self {
+            Self {
+                lamports: 0,
+                data: Vec::new(),
+                owner: system_program::ID,
+            }
+        }
+
+        fn wallet() -> Self {
+            Self {
+                lamports: 1_000_000_000,
+                data: Vec::new(),
+                owner: system_program::ID,
+            }
+        }
+    }
+
+    /// Accounts by address, as the runtime would hold them.
+    type Accounts = BTreeMap<Pubkey, TestAccount>;
+
+    /// Run `ix` through the program entrypoint at unix time `now`, the way
+    /// the runtime would for a transaction or CPI: the accounts are laid
+    /// out in the runtime's input format (so resizing and closing accounts
+    /// behave as on-chain), and written back only if the instruction
+    /// succeeds.
+    fn process(ix: &Instruction, accounts: &mut Accounts, now: i64) -> ProgramResult {
+        static STUBS: Once = Once::new();
+        STUBS.call_once(|| {
+            set_syscall_stubs(Box::new(Sysvars));
+        });
+        NOW.with(|n| n.set(now));
+
+        let mut input = serialize(ix, accounts);
+        let (program_id, infos, data) = unsafe { deserialize(input.as_mut_ptr().cast()) };
+        crate::entry(program_id, &infos, data)?;
+        for info in &infos {
+            let account = TestAccount {
+                lamports: info.lamports(),
+                data: info.data.borrow().to_vec(),
+                owner: *info.owner,
+            };
+            accounts.insert(*info.key, account);
+        }
+        Ok(())
+    }
+
+    /// Encode `ix`'s accounts and data in the runtime's aligned input
+    /// layout, 8-byte aligned as `deserialize` expects.
+    fn serialize(ix: &Instruction, accounts: &Accounts) -> Vec<u64> {
+        let mut input = (ix.accounts.len() as u64).to_le_bytes().to_vec();
+        for (i, meta) in ix.accounts.iter().enumerate() {
+            if let Some(first) = ix.accounts[..i]
+                .iter()
+                .position(|m| m.pubkey == meta.pubkey)
+            {
+                input.extend([first as u8, 0, 0, 0, 0, 0, 0, 0]);
+                continue;
+            }
+            let account = &accounts[&meta.pubkey];
+            input.extend([
+                NON_DUP_MARKER,
+                meta.is_signer as u8,
+                meta.is_writable as u8,
+                0,
+                0,
+                0,
+                0,
+                0,
+            ]);
+            input.extend(meta.pubkey.as_ref());
+            input.extend(account.owner.as_ref());
+            input.extend(account.lamports.to_le_bytes());
+            input.extend((account.data.len() as u64).to_le_bytes());
+            input.extend(&account.data);
+            let len = input.len() + MAX_PERMITTED_DATA_INCREASE;
+            input.resize(len.next_multiple_of(BPF_ALIGN_OF_U128), 0);
+            input.extend(u64::MAX.to_le_bytes());
+        }
+        input.extend((ix.data.len() as u64).to_le_bytes());
+        input.extend(&ix.data);
+        input.extend(ix.program_id.as_ref());
+
+        input
+            .chunks(8)
+            .map(|chunk| {
+                let mut word = [0; 8];
+                word[..chunk.len()].copy_from_slice(chunk);
+                u64::from_ne_bytes(word)
+            })
+            .collect()
+    }
+
+    fn load<T: AccountDeserialize>(accounts: &Accounts, key: &Pubkey) -> T {
+        T::try_deserialize(&mut &accounts[key].data[..]).unwrap()
+    }
+
+    /// Rewrite the stored `T` at `key` in place.
+    fn update<T: AccountSerialize + AccountDeserialize + Space>(
+        accounts: &mut Accounts,
+        key: &Pubkey,
+        f: impl FnOnce(&mut T),
+    ) {
+        let mut value: T = load(accounts, key);
+        f(&mut value);
+        accounts.insert(*key, TestAccount::program(&value));
+    }
+
+    fn fails_with(error: AxiomError) -> ProgramResult {
+        Err(ProgramError::Custom(error.into()))
+    }
+
+    /// A registered agent with its trust history, and a config naming
+    /// `oracle`.
+    fn setup(authority: Pubkey, oracle: Pubkey) -> Accounts {
+        let (profile_key, bump) =
+            Pubkey::find_program_address(&[AGENT_SEED, authority.as_ref()], &ID);
+        let profile = AgentProfile {
+            version: AGENT_PROFILE_VERSION,
+            authority,
+            seed_authority: authority,
+            name: "agent".into(),
+            bump,
+            ..zeroed()
+        };
+        let (history_key, bump) =
+            Pubkey::find_program_address(&[TRUST_HISTORY_SEED, profile_key.as_ref()], &ID);
+        let history = TrustHistory {
+            agent: profile_key,
+            bump,
+            ..zeroed()
+        };
+        let (config_key, bump) = Pubkey::find_program_address(&[CONFIG_SEED], &ID);
+        let config = Config {
+            oracle,
+            bump,
+            ..zeroed()
+        };
+        Accounts::from([
+            (profile_key, TestAccount::program(&profile)),
+            (history_key, TestAccount::program(&history)),
+            (config_key, TestAccount::program(&config)),
+            (oracle, TestAccount::wallet()),
+        ])
+    }
+
+    /// Store an unverified proof of `hash` for the agent registered by
+    /// `authority`, returning its address.
+    fn store_proof(accounts: &mut Accounts, authority: &Pubkey, hash: [u8; 32]) -> Pubkey {
+        let agent = agent_profile_address(authority);
+        let (key, bump) = Pubkey::find_program_address(&[PROOF_SEED, agent.as_ref(), &hash], &ID);
+        let proof = ReasoningProof {
+            agent,
+            proof_hash: hash,
+            domain: ProofDomain::Defi as u8,
+            bump,
+            ..zeroed()
+        };
+        accounts.insert(key, TestAccount::program(&proof));
+        key
+    }
+
+    #[test]
+    fn attestations_must_be_newer_than_the_stored_one() {
+        let (authority, oracle) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let mut accounts = setup(authority, oracle);
+        let attest = |attested_at| {
+            attest_conviction_ix(
+                oracle,
+                &authority,
+                6_000,
+                6_000,
+                6_000,
+                TrustLevel::Medium,
+                attested_at,
+            )
+        };
+
+        assert_eq!(process(&attest(50), &mut accounts, 100), Ok(()));
+        // Replayed, older and future oracle writes leave the profile alone
+        for attested_at in [50, 40] {
+            assert_eq!(
+                process(&attest(attested_at), &mut accounts, 100),
+                fails_with(AxiomError::StaleAttestation)
+            );
+        }
+        assert_eq!(
+            process(&attest(101), &mut accounts, 100),
+            fails_with(AxiomError::AttestationInFuture)
+        );
+        let profile: AgentProfile = load(&accounts, &agent_profile_address(&authority));
+        assert_eq!(profile.attested_at, 50);
+
+        assert_eq!(process(&attest(100), &mut accounts, 100), Ok(()));
+        let profile: AgentProfile = load(&accounts, &agent_profile_address(&authority));
+        assert_eq!(profile.attested_at, 100);
+    }
+
+    #[test]
+    fn compute_budget_instructions_lead_the_transaction() {
+        let ix = Instruction {
+            program_id: ID,
+            accounts: Vec::new(),
+            data: vec![1, 2, 3],
+        };
+        assert_eq!(
+            with_compute_budget(ComputeBudget::default(), ix.clone()),
+            vec![ix.clone()]
+        );
+
+        let budget = ComputeBudget {
+            unit_limit: Some(200_000),
+            priority_fee_microlamports: Some(5_000),
+        };
+        let ixs = with_compute_budget(budget, ix.clone());
+        assert_eq!(ixs.len(), 3);
+        assert_eq!(ixs[0].program_id, COMPUTE_BUDGET_PROGRAM_ID);
+        assert!(ixs[0].accounts.is_empty());
+        assert_eq!(
+            ixs[0].data,
+            [&[2u8][..], &200_000u32.to_le_bytes()].concat()
+        );
+        assert_eq!(ixs[1].program_id, COMPUTE_BUDGET_PROGRAM_ID);
+        assert_eq!(ixs[1].data, [&[3u8][..], &5_000u64.to_le_bytes()].concat());
+        assert_eq!(ixs[2], ix);
+    }
+
+    #[test]
+    fn verify_trust_ix_encodes_the_level() {
+        let ix = verify_trust_ix(&Pubkey::new_unique(), TrustLevel::Medium);
+        assert_eq!(ix.accounts.len(), 1);
+        assert_eq!(
+            ix.data,
+            instruction::VerifyTrust {
+                required: TrustLevel::Medium.as_u8(),
+            }
+            .data()
+        );
+    }
+
+    #[test]
+    fn verify_trust_gates_the_calling_instruction() {
+        let (authority, oracle) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let mut accounts = setup(authority, oracle);
+        let profile_key = agent_profile_address(&authority);
+        let gate = verify_trust_ix(&authority, TrustLevel::Medium);
+
+        for (trust, result) in [
+            (TrustLevel::High, Ok(())),
+            (TrustLevel::Medium, Ok(())),
+            (TrustLevel::Low, fails_with(AxiomError::InsufficientTrust)),
+        ] {
+            let mut profile: AgentProfile = load(&accounts, &profile_key);
+            profile.last_trust_level = trust.as_u8();
+            accounts.insert(profile_key, TestAccount::program(&profile));
+            assert_eq!(process(&gate, &mut accounts, 0), result, "{trust:?}");
+        }
+
+        let unknown = verify_trust_ix(&authority, TrustLevel::Unknown);
+        assert_eq!(
+            process(&unknown, &mut accounts, 0),
+            fails_with(AxiomError::InvalidTrustLevel)
+        );
+    }
+
+    #[test]
+    fn only_the_admin_can_rotate_the_oracle() {
+        let (authority, oracle, admin) = (
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        );
+        let mut accounts = setup(authority, oracle);
+        update(&mut accounts, &config_address(), |config: &mut Config| {
+            config.admin = admin
+        });
+        let rotated = Pubkey::new_unique();
+        accounts.insert(admin, TestAccount::wallet());
+        accounts.insert(rotated, TestAccount::wallet());
+        let attest = |oracle, attested_at| {
+            attest_conviction_ix(
+                oracle,
+                &authority,
+                6_000,
+                6_000,
+                6_000,
+                TrustLevel::Medium,
+                attested_at,
+            )
+        };
+
+        assert_eq!(
+            process(&set_oracle_ix(oracle, rotated), &mut accounts, 100),
+            fails_with(AxiomError::Unauthorized)
+        );
+        assert_eq!(process(&attest(oracle, 50), &mut accounts, 100), Ok(()));
+
+        assert_eq!(
+            process(&set_oracle_ix(admin, rotated), &mut accounts, 100),
+            Ok(())
+        );
+        assert_eq!(
+            process(&attest(oracle, 60), &mut accounts, 100),
+            fails_with(AxiomError::Unauthorized)
+        );
+        assert_eq!(process(&attest(rotated, 70), &mut accounts, 100), Ok(()));
+        let profile: AgentProfile = load(&accounts, &agent_profile_address(&authority));
+        assert_eq!(profile.attested_at, 70);
+    }
+
+    #[test]
+    fn pausing_blocks_attestations_until_resumed() {
+        let (authority, oracle, admin) = (
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        );
+        let mut accounts = setup(authority, oracle);
+        update(&mut accounts, &config_address(), |config: &mut Config| {
+            config.admin = admin
+        });
+        accounts.insert(admin, TestAccount::wallet());
+        let attest = |attested_at| {
+            attest_conviction_ix(
+                oracle,
+                &authority,
+                6_000,
+                6_000,
+                6_000,
+                TrustLevel::Medium,
+                attested_at,
+            )
+        };
+
+        assert_eq!(
+            process(&set_paused_ix(admin, true), &mut accounts, 100),
+            Ok(())
+        );
+        assert_eq!(
+            process(&attest(50), &mut accounts, 100),
+            fails_with(AxiomError::ProgramPaused)
+        );
+        assert_eq!(
+            process(&set_paused_ix(oracle, false), &mut accounts, 100),
+            fails_with(AxiomError::Unauthorized)
+        );
+        let profile: AgentProfile = load(&accounts, &agent_profile_address(&authority));
+        assert_eq!(profile.attested_at, 0);
+
+        assert_eq!(
+            process(&set_paused_ix(admin, false), &mut accounts, 100),
+            Ok(())
+        );
+        assert_eq!(process(&attest(50), &mut accounts, 100), Ok(()));
+        let profile: AgentProfile = load(&accounts, &agent_profile_address(&authority));
+        assert_eq!(profile.attested_at, 50);
+    }
+
+    #[test]
+    fn close_proof_returns_the_rent_to_the_recipient() {
+        let (authority, oracle) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let mut accounts = setup(authority, oracle);
+        let (other, recipient) = (Pubkey::new_unique(), Pubkey::new_unique());
+        accounts.extend(setup(other, oracle));
+        accounts.insert(authority, TestAccount::wallet());
+        accounts.insert(other, TestAccount::wallet());
+        accounts.insert(recipient, TestAccount::wallet());
+        let proof = store_proof(&mut accounts, &authority, [1; 32]);
+        let staked = store_proof(&mut accounts, &authority, [2; 32]);
+        update(&mut accounts, &staked, |proof: &mut ReasoningProof| {
+            proof.stake = Some(stake_address(&staked))
+        });
+
+        // Another agent's owner, through either profile
+        for agent_authority in [other, authority] {
+            assert_eq!(
+                process(
+                    &close_proof_ix(other, &agent_authority, proof, recipient),
+                    &mut accounts,
+                    100
+                ),
+                fails_with(AxiomError::Unauthorized)
+            );
+        }
+        assert_eq!(
+            process(
+                &close_proof_ix(authority, &authority, staked, recipient),
+                &mut accounts,
+                100
+            ),
+            fails_with(AxiomError::ProofHasStake)
+        );
+        let disputed = store_proof(&mut accounts, &authority, [3; 32]);
+        update(&mut accounts, &disputed, |proof: &mut ReasoningProof| {
+            proof.disputed = true
+        });
+        assert_eq!(
+            process(
+                &close_proof_ix(authority, &authority, disputed, recipient),
+                &mut accounts,
+                100
+            ),
+            fails_with(AxiomError::ProofDisputed)
+        );
+
+        let rent = accounts[&proof].lamports;
+        let before = accounts[&recipient].lamports;
+        assert_eq!(
+            process(
+                &close_proof_ix(authority, &authority, proof, recipient),
+                &mut accounts,
+                100
+            ),
+            Ok(())
+        );
+        assert_eq!(accounts[&recipient].lamports, before + rent);
+        assert_eq!(accounts[&proof].lamports, 0);
+        assert!(accounts[&proof].data.is_empty());
+        assert_eq!(accounts[&proof].owner, system_program::ID);
+    }
+
+    #[test]
+    fn slash_moves_stake_to_the_treasury_unless_paused() {
+        let (authority, oracle) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let mut accounts = setup(authority, oracle);
+        let proof = store_proof(&mut accounts, &authority, [1; 32]);
+        let (stake_key, bump) = Pubkey::find_program_address(&[STAKE_SEED, proof.as_ref()], &ID);
+        let stake = StakeAccount {
+            agent: agent_profile_address(&authority),
+            proof,
+            amount: 1_000,
+            bump,
+            ..zeroed()
+        };
+        let mut stake = TestAccount::program(&stake);
+        stake.lamports += 1_000;
+        accounts.insert(stake_key, stake);
+        let (treasury_key, bump) = Pubkey::find_program_address(&[TREASURY_SEED], &ID);
+        let treasury = Treasury { bump, ..zeroed() };
+        accounts.insert(treasury_key, TestAccount::program(&treasury));
+        update(&mut accounts, &proof, |proof: &mut ReasoningProof| {
+            proof.stake = Some(stake_key)
+        });
+        update(
+            &mut accounts,
+            &agent_profile_address(&authority),
+            |profile: &mut AgentProfile| profile.staked_lamports = 1_000,
+        );
+        let slash = slash_ix(oracle, &authority, proof, 5_000);
+
+        update(&mut accounts, &config_address(), |config: &mut Config| {
+            config.paused = true
+        });
+        assert_eq!(
+            process(&slash, &mut accounts, 100),
+            fails_with(AxiomError::ProgramPaused)
+        );
+
+        update(&mut accounts, &config_address(), |config: &mut Config| {
+            config.paused = false
+        });
+        let (stake_before, treasury_before) = (
+            accounts[&stake_key].lamports,
+            accounts[&treasury_key].lamports,
+        );
+        assert_eq!(process(&slash, &mut accounts, 100), Ok(()));
+        assert_eq!(accounts[&stake_key].lamports, stake_before - 500);
+        assert_eq!(accounts[&treasury_key].lamports, treasury_before + 500);
+        assert_eq!(load::<StakeAccount>(&accounts, &stake_key).amount, 500);
+        assert!(load::<ReasoningProof>(&accounts, &proof).disputed);
+        let profile: AgentProfile = load(&accounts, &agent_profile_address(&authority));
+        assert_eq!(profile.staked_lamports, 500);
+    }
+
+    #[test]
+    fn delegates_follow_set_revoke_and_transfer() {
+        let (authority, oracle) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let mut accounts = setup(authority, oracle);
+        let profile_key = agent_profile_address(&authority);
+        let (delegate, new_owner) = (Pubkey::new_unique(), Pubkey::new_unique());
+        accounts.insert(authority, TestAccount::wallet());
+        accounts.insert(new_owner, TestAccount::wallet());
+        let set = set_delegate_ix(authority, &authority, delegate);
+
+        update(&mut accounts, &config_address(), |config: &mut Config| {
+            config.paused = true
+        });
+        assert_eq!(
+            process(&set, &mut accounts, 100),
+            fails_with(AxiomError::ProgramPaused)
+        );
+        update(&mut accounts, &config_address(), |config: &mut Config| {
+            config.paused = false
+        });
+
+        assert_eq!(process(&set, &mut accounts, 100), Ok(()));
+        let profile: AgentProfile = load(&accounts, &profile_key);
+        assert!(profile.can_submit(&authority));
+        assert!(profile.can_submit(&delegate));
+        assert!(!profile.can_submit(&new_owner));
+
+        let revoke = revoke_delegate_ix(authority, &authority);
+        assert_eq!(process(&revoke, &mut accounts, 100), Ok(()));
+        let profile: AgentProfile = load(&accounts, &profile_key);
+        assert!(profile.can_submit(&authority));
+        assert!(!profile.can_submit(&delegate));
+
+        // The previous owner's delegate does not carry over
+        assert_eq!(process(&set, &mut accounts, 100), Ok(()));
+        let transfer = transfer_agent_ownership_ix(authority, &authority, new_owner);
+        assert_eq!(process(&transfer, &mut accounts, 100), Ok(()));
+        let profile: AgentProfile = load(&accounts, &profile_key);
+        assert!(profile.can_submit(&new_owner));
+        assert!(!profile.can_submit(&delegate));
+    }
+
+    #[test]
+    fn only_the_admin_can_withdraw_above_rent() {
+        let (authority, oracle, admin) = (
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        );
+        let mut accounts = setup(authority, oracle);
+        update(&mut accounts, &config_address(), |config: &mut Config| {
+            config.admin = admin
+        });
+        let recipient = Pubkey::new_unique();
+        accounts.insert(admin, TestAccount::wallet());
+        accounts.insert(recipient, TestAccount::wallet());
+        let (treasury_key, bump) = Pubkey::find_program_address(&[TREASURY_SEED], &ID);
+        let mut treasury = TestAccount::program(&Treasury { bump, ..zeroed() });
+        let rent = treasury.lamports;
+        treasury.lamports += 1_000;
+        accounts.insert(treasury_key, treasury);
+
+        assert_eq!(
+            process(
+                &withdraw_treasury_ix(oracle, recipient, 1_000),
+                &mut accounts,
+                100
+            ),
+            fails_with(AxiomError::Unauthorized)
+        );
+        assert_eq!(
+            process(
+                &withdraw_treasury_ix(admin, recipient, 1_001),
+                &mut accounts,
+                100
+            ),
+            fails_with(AxiomError::InsufficientTreasuryFunds)
+        );
+
+        let before = accounts[&recipient].lamports;
+        assert_eq!(
+            process(
+                &withdraw_treasury_ix(admin, recipient, 1_000),
+                &mut accounts,
+                100
+            ),
+            Ok(())
+        );
+        assert_eq!(accounts[&treasury_key].lamports, rent);
+        assert_eq!(accounts[&recipient].lamports, before + 1_000);
+        let treasury: Treasury = load(&accounts, &treasury_key);
+        assert_eq!(treasury.total_withdrawn, 1_000);
+    }
+
+    #[test]
+    fn registering_with_an_attestation_needs_the_oracle_signature() {
+        let (authority, oracle) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let mut accounts = setup(Pubkey::new_unique(), oracle);
+        let profile_key = agent_profile_address(&authority);
+        accounts.insert(profile_key, TestAccount::empty());
+        accounts.insert(trust_history_address(&profile_key), TestAccount::empty());
+        accounts.insert(authority, TestAccount::wallet());
+        accounts.insert(system_program::ID, TestAccount::empty());
+        let attestation = Attestation {
+            score_bps: 6_000,
+            defi_bps: 6_000,
+            prediction_bps: 6_000,
+            trust_level: TrustLevel::Medium.as_u8(),
+            attested_at: 50,
+        };
+        let mut ix =
+            register_agent_with_attestation_ix(authority, oracle, "agent".into(), attestation);
+        for meta in &mut ix.accounts {
+            if meta.pubkey == oracle {
+                meta.is_signer = false;
+            }
+        }
+
+        assert_eq!(
+            process(&ix, &mut accounts, 100),
+            Err(ProgramError::Custom(
+                anchor_lang::error::ErrorCode::AccountNotSigner.into()
+            ))
+        );
+        assert!(accounts[&profile_key].data.is_empty());
+        assert_eq!(accounts[&profile_key].lamports, 0);
+    }
+
+    #[test]
+    fn ownership_transfers_lock_out_the_previous_owner() {
+        let (authority, oracle) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let mut accounts = setup(authority, oracle);
+        let profile_key = agent_profile_address(&authority);
+        let new_owner = Pubkey::new_unique();
+        accounts.insert(authority, TestAccount::wallet());
+        accounts.insert(new_owner, TestAccount::wallet());
+        let transfer = |owner, to| transfer_agent_ownership_ix(owner, &authority, to);
+
+        assert_eq!(
+            process(&transfer(authority, Pubkey::default()), &mut accounts, 100),
+            fails_with(AxiomError::InvalidNewOwner)
+        );
+        update(&mut accounts, &config_address(), |config: &mut Config| {
+            config.paused = true
+        });
+        assert_eq!(
+            process(&transfer(authority, new_owner), &mut accounts, 100),
+            fails_with(AxiomError::ProgramPaused)
+        );
+        update(&mut accounts, &config_address(), |config: &mut Config| {
+            config.paused = false
+        });
+
+        assert_eq!(
+            process(&transfer(authority, new_owner), &mut accounts, 100),
+            Ok(())
+        );
+        let profile: AgentProfile = load(&accounts, &profile_key);
+        assert_eq!(profile.authority, new_owner);
+        assert_eq!(profile.seed_authority, authority);
+        assert!(!profile.can_submit(&authority));
+
+        // The old key no longer passes `has_one = authority`
+        assert_eq!(
+            process(
+                &set_delegate_ix(authority, &authority, authority),
+                &mut accounts,
+                100
+            ),
+            fails_with(AxiomError::Unauthorized)
+        );
+        assert_eq!(
+            process(&transfer(authority, authority), &mut accounts, 100),
+            fails_with(AxiomError::Unauthorized)
+        );
+        assert_eq!(
+            process(&transfer(new_owner, authority), &mut accounts, 100),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn verify_proof_requires_conviction_in_the_proof_domain() {
+        let (authority, oracle) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let mut accounts = setup(authority, oracle);
+        let profile_key = agent_profile_address(&authority);
+        update(&mut accounts, &config_address(), |config: &mut Config| {
+            config.min_defi_bps = 7_000
+        });
+        update(&mut accounts, &profile_key, |profile: &mut AgentProfile| {
+            profile.conviction_bps = 9_500;
+            profile.last_defi_bps = 6_999;
+        });
+        let proof = store_proof(&mut accounts, &authority, [1; 32]);
+        update(&mut accounts, &proof, |proof: &mut ReasoningProof| {
+            proof.expires_at = 200
+        });
+        let verify = verify_proof_ix(&authority, proof);
+
+        // A high aggregate score does not stand in for the domain score
+        assert_eq!(
+            process(&verify, &mut accounts, 100),
+            fails_with(AxiomError::InsufficientDomainConviction)
+        );
+        assert!(!load::<ReasoningProof>(&accounts, &proof).verified);
+
+        update(&mut accounts, &profile_key, |profile: &mut AgentProfile| {
+            profile.last_defi_bps = 7_000
+        });
+        assert_eq!(process(&verify, &mut accounts, 100), Ok(()));
+        assert!(load::<ReasoningProof>(&accounts, &proof).verified);
+    }
+
+    #[test]
+    fn attestation_batches_apply_all_entries_or_none() {
+        let (first, second, oracle) = (
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        );
+        let mut accounts = setup(first, oracle);
+        accounts.extend(setup(second, oracle));
+        let (first_key, second_key) = (
+            agent_profile_address(&first),
+            agent_profile_address(&second),
+        );
+        let batch = |attested_at| {
+            let attestation = |score_bps| Attestation {
+                score_bps,
+                defi_bps: score_bps,
+                prediction_bps: score_bps,
+                trust_level: TrustLevel::Medium.as_u8(),
+                attested_at,
+            };
+            attest_conviction_batch_ix(
+                oracle,
+                vec![(first, attestation(6_000)), (second, attestation(6_500))],
+            )
+        };
+
+        assert_eq!(process(&batch(50), &mut accounts, 100), Ok(()));
+        for (key, score) in [(first_key, 6_000), (second_key, 6_500)] {
+            let profile: AgentProfile = load(&accounts, &key);
+            assert_eq!((profile.conviction_bps, profile.attested_at), (score, 50));
+        }
+
+        // Metas are config, oracle, then each entry's profile and history.
+        // A copy of the second profile outside its PDA:
+        let forged = Pubkey::new_unique();
+        let copy = TestAccount::program(&load::<AgentProfile>(&accounts, &second_key));
+        accounts.insert(forged, copy);
+        let mut forged_profile = batch(60);
+        forged_profile.accounts[4].pubkey = forged;
+        // the first agent's trust history paired with the second agent:
+        let mut swapped_history = batch(60);
+        swapped_history.accounts[5].pubkey = trust_history_address(&first_key);
+        // and a read-only entry.
+        let mut read_only = batch(60);
+        read_only.accounts[5].is_writable = false;
+        for ix in [forged_profile, swapped_history, read_only] {
+            assert_eq!(
+                process(&ix, &mut accounts, 100),
+                fails_with(AxiomError::BatchAccountMismatch)
+            );
+        }
+
+        accounts.get_mut(&second_key).unwrap().owner = Pubkey::new_unique();
+        assert_eq!(
+            process(&batch(60), &mut accounts, 100),
+            Err(ProgramError::Custom(
+                anchor_lang::error::ErrorCode::AccountOwnedByWrongProgram.into()
+            ))
+        );
+        accounts.get_mut(&second_key).unwrap().owner = ID;
+
+        // The valid first entry was not applied either
+        for key in [first_key, second_key] {
+            let profile: AgentProfile = load(&accounts, &key);
+            assert_eq!(profile.attested_at, 50);
+        }
+    }
+}
@@ -1,7 +1,31 @@
 use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+
+pub mod hash;
+#[cfg(feature = "client")]
+pub mod instructions;
+#[cfg(feature = "client")]
+pub mod reader;
 
 declare_id!("CZcvoryaQNrtZ3qb3gC1h9opcYpzEP1D9Mu1RVwFQeBu");
 
+// ─── PDA Seeds ─────────────────────────────────────────────────────────────
+
+/// Seed prefix for `AgentProfile` PDAs: `[AGENT_SEED, seed_authority]`
+pub const AGENT_SEED: &[u8] = b"agent";
+/// Seed prefix for `ReasoningCommitment` PDAs: `[COMMITMENT_SEED, agent_profile, nonce_le]`
+pub const COMMITMENT_SEED: &[u8] = b"commitment";
+/// Seed prefix for `ReasoningProof` PDAs: `[PROOF_SEED, agent_profile, proof_hash]`
+pub const PROOF_SEED: &[u8] = b"proof";
+/// Seed for the singleton `Config` PDA
+pub const CONFIG_SEED: &[u8] = b"config";
+/// Seed prefix for `StakeAccount` PDAs: `[STAKE_SEED, proof]`
+pub const STAKE_SEED: &[u8] = b"stake";
+/// Seed for the singleton treasury PDA
+pub const TREASURY_SEED: &[u8] = b"treasury";
+/// Seed prefix for `TrustHistory` PDAs: `[TRUST_HISTORY_SEED, agent_profile]`
+pub const TRUST_HISTORY_SEED: &[u8] = b"trust_history";
+
 /// SOLPRISM — Verifiable AI Reasoning on Solana
 /// 
 /// Agents commit hashes of their reasoning before executing actions.
@@ -11,22 +35,191 @@ declare_id!("CZcvoryaQNrtZ3qb3gC1h9opcYpzEP1D9Mu1RVwFQeBu");
 pub mod axiom {
     use super::*;
 
-    /// Register a new agent profile on SOLPRISM.
+    /// Register a new agent profile on SOLPRISM, along with the trust
+    /// history its attestations are recorded in.
+    /// 
+    /// Fails with `AgentAlreadyRegistered` if the authority already has one.
     pub fn register_agent(
         ctx: Context<RegisterAgent>,
         name: String,
     ) -> Result<()> {
-        require!(name.len() <= 64, AxiomError::NameTooLong);
-        require!(!name.is_empty(), AxiomError::NameEmpty);
+        require!(!ctx.accounts.config.paused, AxiomError::ProgramPaused);
+        
+        let now = Clock::get()?.unix_timestamp;
+        let authority = ctx.accounts.authority.key();
+        let agent_key = ctx.accounts.agent_profile.key();
+        let bump = ctx.bumps.agent_profile;
+        
+        let profile = AgentProfile::new(authority, name, bump, now)?;
+        let info = ctx.accounts.agent_profile.to_account_info();
+        create_pda_account(
+            &ctx.accounts.authority.to_account_info(),
+            &info,
+            &ctx.accounts.system_program.to_account_info(),
+            AgentProfile::LEN,
+            &[AGENT_SEED, authority.as_ref(), &[bump]],
+        )?;
+        profile.try_serialize(&mut &mut info.try_borrow_mut_data()?[..])?;
+        
+        let history = TrustHistory::new(agent_key, ctx.bumps.trust_history);
+        ctx.accounts.trust_history.set_inner(history);
+        
+        emit!(AgentRegistered {
+            authority,
+            timestamp: now,
+        });
+        
+        Ok(())
+    }
+
+    /// Register an agent and record its initial conviction in one
+    /// transaction. The config oracle must co-sign; the attestation is
+    /// validated exactly as in `attest_conviction`.
+    pub fn register_agent_with_attestation(
+        ctx: Context<RegisterAgentWithAttestation>,
+        name: String,
+        attestation: Attestation,
+    ) -> Result<()> {
+        require!(!ctx.accounts.config.paused, AxiomError::ProgramPaused);
+        
+        let now = Clock::get()?.unix_timestamp;
+        let authority = ctx.accounts.authority.key();
+        let agent_key = ctx.accounts.agent_profile.key();
+        let bump = ctx.bumps.agent_profile;
+        
+        let mut profile = AgentProfile::new(authority, name, bump, now)?;
+        let info = ctx.accounts.agent_profile.to_account_info();
+        create_pda_account(
+            &ctx.accounts.authority.to_account_info(),
+            &info,
+            &ctx.accounts.system_program.to_account_info(),
+            AgentProfile::LEN,
+            &[AGENT_SEED, authority.as_ref(), &[bump]],
+        )?;
+        
+        let history = TrustHistory::new(agent_key, ctx.bumps.trust_history);
+        ctx.accounts.trust_history.set_inner(history);
+        
+        emit!(AgentRegistered {
+            authority,
+            timestamp: now,
+        });
+        
+        attestation.apply(
+            agent_key,
+            &mut profile,
+            &mut ctx.accounts.trust_history,
+            &ctx.accounts.config,
+            now,
+        )?;
+        profile.try_serialize(&mut &mut info.try_borrow_mut_data()?[..])?;
+        
+        Ok(())
+    }
+
+    /// Upgrade a legacy (pre-versioning) agent profile to the current layout.
+    /// 
+    /// Legacy accounts are recognized by their size, reallocated to the
+    /// current size (the authority pays the extra rent), and rewritten
+    /// with every existing field preserved and new fields defaulted.
+    /// Current-version profiles are rejected with `AlreadyMigrated`.
+    pub fn migrate_agent_profile(ctx: Context<MigrateAgentProfile>) -> Result<()> {
+        let info = ctx.accounts.agent_profile.to_account_info();
+        
+        let legacy = AgentProfileV1::from_account_data(&info.try_borrow_data()?)?;
+        require_keys_eq!(
+            legacy.authority,
+            ctx.accounts.authority.key(),
+            AxiomError::Unauthorized
+        );
+        
+        let new_len = AgentProfile::LEN;
+        let rent_due = Rent::get()?
+            .minimum_balance(new_len)
+            .saturating_sub(info.lamports());
+        if rent_due > 0 {
+            system_program::transfer(
+                CpiContext::new(
+                    ctx.accounts.system_program.to_account_info(),
+                    system_program::Transfer {
+                        from: ctx.accounts.authority.to_account_info(),
+                        to: info.clone(),
+                    },
+                ),
+                rent_due,
+            )?;
+        }
+        info.resize(new_len)?;
+        
+        let profile = legacy.upgrade();
+        profile.try_serialize(&mut &mut info.try_borrow_mut_data()?[..])?;
+        
+        Ok(())
+    }
+
+    /// Authorize a hot wallet to submit proofs on the agent's behalf.
+    /// 
+    /// Replaces any existing delegate. Blocked while the program is
+    /// paused; revoking is not, so a leaked delegate key can always be cut
+    /// off.
+    pub fn set_delegate(
+        ctx: Context<UpdateAgent>,
+        delegate: Pubkey,
+    ) -> Result<()> {
+        require!(!ctx.accounts.config.paused, AxiomError::ProgramPaused);
+        
+        let agent_key = ctx.accounts.agent_profile.key();
+        ctx.accounts.agent_profile.delegate = Some(delegate);
+        
+        emit!(DelegateUpdated {
+            agent: agent_key,
+            delegate: Some(delegate),
+        });
+        
+        Ok(())
+    }
+
+    /// Revoke the agent's delegate; only the authority can submit afterwards.
+    pub fn revoke_delegate(ctx: Context<UpdateAgent>) -> Result<()> {
+        let agent_key = ctx.accounts.agent_profile.key();
+        ctx.accounts.agent_profile.delegate = None;
+        
+        emit!(DelegateUpdated {
+            agent: agent_key,
+            delegate: None,
+        });
+        
+        Ok(())
+    }
+
+    /// Hand the agent profile to a new owner key (e.g. after a key
+    /// compromise).
+    /// 
+    /// The profile PDA stays where it is, derived from `seed_authority`,
+    /// so proofs, stakes, history and counters carry over untouched; only
+    /// `authority` changes. Any delegate is cleared, since it was chosen
+    /// by the previous owner. Blocked while the program is paused.
+    pub fn transfer_agent_ownership(
+        ctx: Context<UpdateAgent>,
+        new_owner: Pubkey,
+    ) -> Result<()> {
+        require!(!ctx.accounts.config.paused, AxiomError::ProgramPaused);
+        require!(new_owner != Pubkey::default(), AxiomError::InvalidNewOwner);
+        
+        let agent_key = ctx.accounts.agent_profile.key();
+        let now = Clock::get()?.unix_timestamp;
         
         let profile = &mut ctx.accounts.agent_profile;
-        profile.authority = ctx.accounts.authority.key();
-        profile.name = name;
-        profile.total_commitments = 0;
-        profile.total_verified = 0;
-        profile.accountability_score = 10000; // Start at 100.00% (basis points)
-        profile.created_at = Clock::get()?.unix_timestamp;
-        profile.bump = ctx.bumps.agent_profile;
+        let previous = profile.authority;
+        profile.authority = new_owner;
+        profile.delegate = None;
+        
+        emit!(AgentOwnershipTransferred {
+            agent: agent_key,
+            previous,
+            new_owner,
+            timestamp: now,
+        });
         
         Ok(())
     }
@@ -113,131 +306,1854 @@ pub mod axiom {
         
         Ok(())
     }
-}
-
-// ─── Account Structs ───────────────────────────────────────────────────────
-
-#[account]
-#[derive(InitSpace)]
-pub struct AgentProfile {
-    /// The agent's authority (wallet that controls this profile)
-    pub authority: Pubkey,
-    /// Display name (max 64 chars)
-    #[max_len(64)]
-    pub name: String,
-    /// Total reasoning commitments published
-    pub total_commitments: u64,
-    /// Total commitments that have been revealed
-    pub total_verified: u64,
-    /// Accountability score in basis points (0-10000 = 0%-100%)
-    pub accountability_score: u16,
-    /// When the agent registered
-    pub created_at: i64,
-    /// PDA bump
-    pub bump: u8,
-}
 
-#[account]
-#[derive(InitSpace)]
-pub struct ReasoningCommitment {
-    /// The agent profile this commitment belongs to
-    pub agent: Pubkey,
-    /// The authority that created this commitment
-    pub authority: Pubkey,
-    /// SHA-256 hash of the full reasoning trace
-    pub commitment_hash: [u8; 32],
-    /// Type of action (e.g., "trade", "audit", "rebalance")
-    #[max_len(32)]
-    pub action_type: String,
-    /// Confidence score (0-100)
-    pub confidence: u8,
-    /// Unix timestamp when committed
-    pub timestamp: i64,
-    /// Whether the full reasoning has been revealed
-    pub revealed: bool,
-    /// URI to the full reasoning (IPFS, Arweave, etc.)
-    #[max_len(256)]
-    pub reasoning_uri: String,
-    /// Nonce for unique PDA derivation (allows multiple commitments)
-    pub nonce: u64,
-    /// PDA bump
-    pub bump: u8,
-}
+    /// Store a reasoning proof hash onchain.
+    /// 
+    /// Anchors the exact reasoning artifact an agent was assessed on,
+    /// so off-chain verifiers can later prove which proof they checked.
+    /// Each (agent, proof_hash) pair can only be stored once, and the
+    /// proof can only be verified until `ttl_seconds` have elapsed.
+    /// `nonce` must equal the profile's next expected nonce, so a relayed
+    /// submission cannot be replayed. Either the agent's authority or its
+    /// current delegate may submit (and pays for the account).
+    pub fn store_reasoning_proof(
+        ctx: Context<StoreReasoningProof>,
+        proof_hash: [u8; 32],
+        domain: u8,
+        ttl_seconds: i64,
+        nonce: u64,
+    ) -> Result<()> {
+        require!(!ctx.accounts.config.paused, AxiomError::ProgramPaused);
+        require!(proof_hash != [0u8; 32], AxiomError::ProofHashEmpty);
+        require!(ProofDomain::from_u8(domain).is_some(), AxiomError::InvalidDomain);
+        require!(ttl_seconds > 0, AxiomError::InvalidTtl);
+        
+        let now = Clock::get()?.unix_timestamp;
+        let expires_at = now.checked_add(ttl_seconds)
+            .ok_or(AxiomError::Overflow)?;
+        let verifiable_after = now.checked_add(ctx.accounts.config.challenge_window_secs)
+            .ok_or(AxiomError::Overflow)?;
+        
+        ctx.accounts.agent_profile.record_proofs(nonce, 1, now)?;
+        
+        let agent_key = ctx.accounts.agent_profile.key();
+        
+        let proof = &mut ctx.accounts.proof;
+        proof.agent = agent_key;
+        proof.proof_hash = proof_hash;
+        proof.domain = domain;
+        proof.created_at = now;
+        proof.verified = false;
+        proof.expires_at = expires_at;
+        proof.verifiable_after = verifiable_after;
+        proof.disputed = false;
+        proof.stake = None;
+        proof.bump = ctx.bumps.proof;
+        
+        // agent + proof_hash are the proof PDA seeds
+        emit!(ProofStored {
+            agent: agent_key,
+            proof_hash,
+            domain,
+            timestamp: now,
+        });
+        
+        Ok(())
+    }
 
-// ─── Instruction Contexts ──────────────────────────────────────────────────
+    /// Store several reasoning proofs in one instruction.
+    /// 
+    /// Each proof's PDA is passed, in order, via `remaining_accounts`
+    /// and created here. All proofs share one TTL, and the whole batch
+    /// consumes a single nonce. The batch fails as a whole if any hash is
+    /// empty or repeated. Either the authority or its delegate may submit.
+    pub fn store_reasoning_proofs<'info>(
+        ctx: Context<'_, '_, 'info, 'info, StoreReasoningProofs<'info>>,
+        proofs: Vec<ProofInput>,
+        ttl_seconds: i64,
+        nonce: u64,
+    ) -> Result<()> {
+        require!(!ctx.accounts.config.paused, AxiomError::ProgramPaused);
+        require!(
+            !proofs.is_empty() && proofs.len() <= MAX_PROOF_BATCH,
+            AxiomError::InvalidBatchSize
+        );
+        require!(
+            ctx.remaining_accounts.len() == proofs.len(),
+            AxiomError::BatchAccountMismatch
+        );
+        require!(ttl_seconds > 0, AxiomError::InvalidTtl);
+        
+        for (i, input) in proofs.iter().enumerate() {
+            require!(input.proof_hash != [0u8; 32], AxiomError::ProofHashEmpty);
+            require!(ProofDomain::from_u8(input.domain).is_some(), AxiomError::InvalidDomain);
+            require!(
+                !proofs[..i].iter().any(|p| p.proof_hash == input.proof_hash),
+                AxiomError::DuplicateProofHash
+            );
+        }
+        
+        let now = Clock::get()?.unix_timestamp;
+        let expires_at = now.checked_add(ttl_seconds)
+            .ok_or(AxiomError::Overflow)?;
+        let verifiable_after = now.checked_add(ctx.accounts.config.challenge_window_secs)
+            .ok_or(AxiomError::Overflow)?;
+        
+        ctx.accounts.agent_profile.record_proofs(nonce, proofs.len() as u64, now)?;
+        
+        let agent_key = ctx.accounts.agent_profile.key();
+        
+        for (input, account) in proofs.iter().zip(ctx.remaining_accounts.iter()) {
+            let (expected, bump) = Pubkey::find_program_address(
+                &[PROOF_SEED, agent_key.as_ref(), &input.proof_hash],
+                ctx.program_id,
+            );
+            require_keys_eq!(account.key(), expected, AxiomError::BatchAccountMismatch);
+            
+            create_pda_account(
+                &ctx.accounts.submitter.to_account_info(),
+                account,
+                &ctx.accounts.system_program.to_account_info(),
+                ReasoningProof::LEN,
+                &[PROOF_SEED, agent_key.as_ref(), &input.proof_hash, &[bump]],
+            )?;
+            
+            let proof = ReasoningProof {
+                agent: agent_key,
+                proof_hash: input.proof_hash,
+                domain: input.domain,
+                created_at: now,
+                verified: false,
+                expires_at,
+                verifiable_after,
+                disputed: false,
+                stake: None,
+                bump,
+            };
+            proof.try_serialize(&mut &mut account.try_borrow_mut_data()?[..])?;
+            
+            emit!(ProofStored {
+                agent: agent_key,
+                proof_hash: input.proof_hash,
+                domain: input.domain,
+                timestamp: now,
+            });
+        }
+        
+        Ok(())
+    }
 
-#[derive(Accounts)]
-#[instruction(name: String)]
-pub struct RegisterAgent<'info> {
-    #[account(
-        init,
-        payer = authority,
-        space = 8 + AgentProfile::INIT_SPACE,
-        seeds = [b"agent", authority.key().as_ref()],
-        bump
-    )]
-    pub agent_profile: Account<'info, AgentProfile>,
-    
-    #[account(mut)]
-    pub authority: Signer<'info>,
-    
-    pub system_program: Program<'info, System>,
-}
+    /// Mark a reasoning proof as verified.
+    /// 
+    /// Permissionless: anyone may finalize a proof, but only once its
+    /// challenge window has passed and while it has not expired. Expired
+    /// or disputed proofs can never become verified. If the config sets a
+    /// minimum for the proof's domain, the agent's attested conviction in
+    /// that domain must meet it. Blocked while the program is paused.
+    pub fn verify_proof(ctx: Context<VerifyProof>) -> Result<()> {
+        require!(!ctx.accounts.config.paused, AxiomError::ProgramPaused);
+        
+        let now = Clock::get()?.unix_timestamp;
+        
+        if let Some(domain) = ctx.accounts.proof.proof_domain() {
+            let required = ctx.accounts.config.min_domain_bps(domain);
+            let actual = ctx.accounts.agent_profile.domain_bps(domain).unwrap_or(0);
+            require!(actual >= required, AxiomError::InsufficientDomainConviction);
+        }
+        
+        let proof = &mut ctx.accounts.proof;
+        proof.check_verifiable(now)?;
+        proof.verified = true;
+        
+        emit!(ProofVerified {
+            agent: proof.agent,
+            proof: proof.key(),
+            timestamp: now,
+        });
+        
+        Ok(())
+    }
 
-#[derive(Accounts)]
-#[instruction(commitment_hash: [u8; 32], action_type: String, confidence: u8, nonce: u64)]
-pub struct CommitReasoning<'info> {
-    #[account(
-        init,
-        payer = authority,
-        space = 8 + ReasoningCommitment::INIT_SPACE,
-        seeds = [
-            b"commitment",
-            agent_profile.key().as_ref(),
-            &nonce.to_le_bytes()
-        ],
-        bump
-    )]
-    pub commitment: Account<'info, ReasoningCommitment>,
-    
-    #[account(
-        mut,
-        seeds = [b"agent", authority.key().as_ref()],
-        bump = agent_profile.bump,
-        has_one = authority
-    )]
-    pub agent_profile: Account<'info, AgentProfile>,
-    
-    #[account(mut)]
-    pub authority: Signer<'info>,
-    
-    pub system_program: Program<'info, System>,
-}
+    /// Dispute an unverified proof, permanently blocking its verification.
+    /// Only the config oracle may challenge.
+    pub fn challenge_proof(ctx: Context<ChallengeProof>) -> Result<()> {
+        require!(!ctx.accounts.config.paused, AxiomError::ProgramPaused);
+        
+        let now = Clock::get()?.unix_timestamp;
+        
+        let proof = &mut ctx.accounts.proof;
+        require!(!proof.verified, AxiomError::ProofAlreadyVerified);
+        
+        proof.disputed = true;
+        
+        emit!(ProofChallenged {
+            agent: proof.agent,
+            proof: proof.key(),
+            timestamp: now,
+        });
+        
+        Ok(())
+    }
 
-#[derive(Accounts)]
-pub struct RevealReasoning<'info> {
-    #[account(
-        mut,
-        has_one = authority,
-        constraint = commitment.agent == agent_profile.key() @ AxiomError::AgentMismatch
-    )]
-    pub commitment: Account<'info, ReasoningCommitment>,
-    
-    #[account(
-        mut,
-        seeds = [b"agent", authority.key().as_ref()],
-        bump = agent_profile.bump,
-        has_one = authority
-    )]
-    pub agent_profile: Account<'info, AgentProfile>,
-    
-    pub authority: Signer<'info>,
-}
+    /// Close a reasoning proof and reclaim its rent.
+    /// 
+    /// Only the owning agent may close its proofs. The lamports go to
+    /// the given recipient and the account data is wiped by Anchor.
+    /// Disputed proofs stay open, so the dispute cannot be erased by
+    /// closing the proof and storing the same hash again.
+    pub fn close_proof(_ctx: Context<CloseProof>) -> Result<()> {
+        Ok(())
+    }
 
-// ─── Events ────────────────────────────────────────────────────────────────
+    /// Put lamports behind a reasoning proof.
+    /// 
+    /// The stake is locked until the proof expires, so an agent cannot
+    /// withdraw its skin in the game while the proof is still live.
+    pub fn stake_on_proof(
+        ctx: Context<StakeOnProof>,
+        amount: u64,
+    ) -> Result<()> {
+        require!(!ctx.accounts.config.paused, AxiomError::ProgramPaused);
+        require!(amount > 0, AxiomError::InvalidStakeAmount);
+        
+        let now = Clock::get()?.unix_timestamp;
+        require!(now < ctx.accounts.proof.expires_at, AxiomError::ProofExpired);
+        
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: ctx.accounts.authority.to_account_info(),
+                    to: ctx.accounts.stake.to_account_info(),
+                },
+            ),
+            amount,
+        )?;
+        
+        let agent_key = ctx.accounts.agent_profile.key();
+        let proof_key = ctx.accounts.proof.key();
+        let stake_key = ctx.accounts.stake.key();
+        // Lock for the proof's lifetime, but never less than the config minimum
+        let min_locked_until = now.checked_add(ctx.accounts.config.min_stake_lock_secs)
+            .ok_or(AxiomError::Overflow)?;
+        let locked_until = ctx.accounts.proof.expires_at.max(min_locked_until);
+        
+        let stake = &mut ctx.accounts.stake;
+        stake.agent = agent_key;
+        stake.proof = proof_key;
+        stake.amount = amount;
+        stake.locked_until = locked_until;
+        stake.bump = ctx.bumps.stake;
+        
+        ctx.accounts.proof.stake = Some(stake_key);
+        
+        let profile = &mut ctx.accounts.agent_profile;
+        profile.staked_lamports = profile.staked_lamports.checked_add(amount)
+            .ok_or(AxiomError::Overflow)?;
+        
+        emit!(ProofStaked {
+            agent: agent_key,
+            proof: proof_key,
+            amount,
+            locked_until,
+        });
+        
+        Ok(())
+    }
 
-#[event]
-pub struct ReasoningCommitted {
+    /// Withdraw a stake (and its rent) once the lock has passed.
+    pub fn unstake(ctx: Context<Unstake>) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        require!(now >= ctx.accounts.stake.locked_until, AxiomError::StakeLocked);
+        
+        ctx.accounts.proof.stake = None;
+        
+        let agent_key = ctx.accounts.agent_profile.key();
+        let profile = &mut ctx.accounts.agent_profile;
+        profile.staked_lamports = profile.staked_lamports.checked_sub(ctx.accounts.stake.amount)
+            .ok_or(AxiomError::Overflow)?;
+        if profile.cap_unbacked_high(&ctx.accounts.config) {
+            emit!(TrustLevelCapped {
+                agent: agent_key,
+                trust_level: profile.last_trust_level,
+                timestamp: now,
+            });
+        }
+        
+        emit!(ProofUnstaked {
+            agent: ctx.accounts.stake.agent,
+            proof: ctx.accounts.proof.key(),
+            amount: ctx.accounts.stake.amount,
+        });
+        
+        Ok(())
+    }
+
+    /// Penalize a disproven proof by moving part of its stake to the treasury.
+    /// 
+    /// Only the oracle may slash. The slashed amount is a basis-point
+    /// fraction of the *remaining* stake, so repeated slashes can never
+    /// take more than what is left. A slashed proof is marked disputed so
+    /// it can never be verified again. Blocked while the program is paused.
+    pub fn slash(ctx: Context<Slash>, bps: u16) -> Result<()> {
+        require!(!ctx.accounts.config.paused, AxiomError::ProgramPaused);
+        require!(bps <= MAX_BPS, AxiomError::InvalidBasisPoints);
+        
+        let stake_amount = ctx.accounts.stake.amount;
+        let slashed = (stake_amount as u128)
+            .checked_mul(bps as u128)
+            .ok_or(AxiomError::Overflow)?
+            / MAX_BPS as u128;
+        let slashed = slashed as u64;
+        let remaining = stake_amount.checked_sub(slashed)
+            .ok_or(AxiomError::Overflow)?;
+        
+        ctx.accounts.stake.sub_lamports(slashed)?;
+        ctx.accounts.treasury.add_lamports(slashed)?;
+        ctx.accounts.stake.amount = remaining;
+        ctx.accounts.treasury.total_collected = ctx.accounts.treasury.total_collected
+            .checked_add(slashed)
+            .ok_or(AxiomError::Overflow)?;
+        // Disputed, not just unverified: verify_proof is permissionless
+        ctx.accounts.proof.verified = false;
+        ctx.accounts.proof.disputed = true;
+        
+        let now = Clock::get()?.unix_timestamp;
+        let agent_key = ctx.accounts.agent_profile.key();
+        let profile = &mut ctx.accounts.agent_profile;
+        profile.staked_lamports = profile.staked_lamports.checked_sub(slashed)
+            .ok_or(AxiomError::Overflow)?;
+        if profile.cap_unbacked_high(&ctx.accounts.config) {
+            emit!(TrustLevelCapped {
+                agent: agent_key,
+                trust_level: profile.last_trust_level,
+                timestamp: now,
+            });
+        }
+        
+        emit!(ProofSlashed {
+            agent: ctx.accounts.stake.agent,
+            proof: ctx.accounts.proof.key(),
+            bps,
+            amount: slashed,
+            remaining,
+            timestamp: now,
+        });
+        
+        Ok(())
+    }
+
+    /// Create the treasury that collects slashed stakes.
+    pub fn initialize_treasury(ctx: Context<InitializeTreasury>) -> Result<()> {
+        let treasury = &mut ctx.accounts.treasury;
+        treasury.total_collected = 0;
+        treasury.total_withdrawn = 0;
+        treasury.bump = ctx.bumps.treasury;
+        
+        Ok(())
+    }
+
+    /// Withdraw collected lamports from the treasury.
+    /// 
+    /// Only the config admin may withdraw, and never below the treasury's
+    /// rent-exempt minimum.
+    pub fn withdraw_treasury(
+        ctx: Context<WithdrawTreasury>,
+        amount: u64,
+    ) -> Result<()> {
+        let info = ctx.accounts.treasury.to_account_info();
+        let available = info.lamports()
+            .saturating_sub(Rent::get()?.minimum_balance(info.data_len()));
+        require!(amount <= available, AxiomError::InsufficientTreasuryFunds);
+        
+        ctx.accounts.treasury.sub_lamports(amount)?;
+        ctx.accounts.recipient.add_lamports(amount)?;
+        ctx.accounts.treasury.total_withdrawn = ctx.accounts.treasury.total_withdrawn
+            .checked_add(amount)
+            .ok_or(AxiomError::Overflow)?;
+        
+        Ok(())
+    }
+
+    /// Create the program config, designating the conviction oracle.
+    /// 
+    /// Only the program's upgrade authority may initialize, so the config
+    /// cannot be claimed by whoever front-runs it after deploy. The signer
+    /// becomes the config admin.
+    pub fn initialize_config(
+        ctx: Context<InitializeConfig>,
+        oracle: Pubkey,
+    ) -> Result<()> {
+        let config = &mut ctx.accounts.config;
+        config.admin = ctx.accounts.admin.key();
+        config.oracle = oracle;
+        config.paused = false;
+        config.min_trust_bps = 0;
+        config.challenge_window_secs = 0;
+        config.min_stake_for_high = 0;
+        config.min_defi_bps = 0;
+        config.min_prediction_bps = 0;
+        config.min_stake_lock_secs = 0;
+        config.bump = ctx.bumps.config;
+        
+        Ok(())
+    }
+
+    /// Rotate the oracle allowed to attest conviction scores.
+    pub fn set_oracle(
+        ctx: Context<UpdateConfig>,
+        new_oracle: Pubkey,
+    ) -> Result<()> {
+        ctx.accounts.config.oracle = new_oracle;
+        
+        Ok(())
+    }
+
+    /// Pause or resume state-changing instructions (incident response).
+    pub fn set_paused(
+        ctx: Context<UpdateConfig>,
+        paused: bool,
+    ) -> Result<()> {
+        ctx.accounts.config.paused = paused;
+        
+        Ok(())
+    }
+
+    /// Set the conviction floor below which no agent may be attested
+    /// above Low trust.
+    pub fn set_min_trust_bps(
+        ctx: Context<UpdateConfig>,
+        min_trust_bps: u16,
+    ) -> Result<()> {
+        require!(min_trust_bps <= MAX_BPS, AxiomError::InvalidBasisPoints);
+        ctx.accounts.config.min_trust_bps = min_trust_bps;
+        
+        Ok(())
+    }
+
+    /// Set how long new proofs stay open to challenges before they can be
+    /// verified. Applies to proofs stored after the change.
+    pub fn set_challenge_window(
+        ctx: Context<UpdateConfig>,
+        challenge_window_secs: i64,
+    ) -> Result<()> {
+        require!(challenge_window_secs >= 0, AxiomError::InvalidChallengeWindow);
+        ctx.accounts.config.challenge_window_secs = challenge_window_secs;
+        
+        Ok(())
+    }
+
+    /// Set the stake an agent needs before it can be attested at High
+    /// trust (zero disables the requirement), and the minimum time new
+    /// stakes stay locked, so High cannot be backed by a stake that is
+    /// withdrawn moments later.
+    pub fn set_min_stake_for_high(
+        ctx: Context<UpdateConfig>,
+        min_stake_for_high: u64,
+        min_stake_lock_secs: i64,
+    ) -> Result<()> {
+        require!(min_stake_lock_secs >= 0, AxiomError::InvalidStakeLock);
+        
+        let config = &mut ctx.accounts.config;
+        config.min_stake_for_high = min_stake_for_high;
+        config.min_stake_lock_secs = min_stake_lock_secs;
+        
+        Ok(())
+    }
+
+    /// Set the domain conviction an agent needs before its proofs in that
+    /// domain can be verified. Zero disables the requirement.
+    pub fn set_min_domain_bps(
+        ctx: Context<UpdateConfig>,
+        min_defi_bps: u16,
+        min_prediction_bps: u16,
+    ) -> Result<()> {
+        require!(min_defi_bps <= MAX_BPS, AxiomError::InvalidBasisPoints);
+        require!(min_prediction_bps <= MAX_BPS, AxiomError::InvalidBasisPoints);
+        
+        let config = &mut ctx.accounts.config;
+        config.min_defi_bps = min_defi_bps;
+        config.min_prediction_bps = min_prediction_bps;
+        
+        Ok(())
+    }
+
+    /// Create the trust history ring buffer for an agent registered before
+    /// registration created it. Must exist before the agent can be
+    /// attested; any signer may pay for it.
+    pub fn initialize_trust_history(ctx: Context<InitializeTrustHistory>) -> Result<()> {
+        let agent_key = ctx.accounts.agent_profile.key();
+        let history = TrustHistory::new(agent_key, ctx.bumps.trust_history);
+        ctx.accounts.trust_history.set_inner(history);
+        
+        Ok(())
+    }
+
+    /// Record the latest off-chain conviction score for an agent.
+    /// 
+    /// Scores are basis points (0-10000) so no floats touch the chain.
+    /// Only the oracle designated in the program config may attest.
+    /// The oracle may record a lower level than the score alone would
+    /// give (e.g. for failed off-chain checks), but never a higher one.
+    /// High also requires `Config::min_stake_for_high` lamports staked;
+    /// a High claim for an agent without it is recorded as Medium.
+    /// 
+    /// `attested_at` is the oracle's source timestamp for the score. It
+    /// must be newer than the stored one, so reordered oracle transactions
+    /// cannot overwrite a fresher attestation with a stale one.
+    pub fn attest_conviction(
+        ctx: Context<AttestConviction>,
+        score_bps: u16,
+        defi_bps: u16,
+        prediction_bps: u16,
+        trust_level: u8,
+        attested_at: i64,
+    ) -> Result<()> {
+        require!(!ctx.accounts.config.paused, AxiomError::ProgramPaused);
+        
+        let agent_key = ctx.accounts.agent_profile.key();
+        let now = Clock::get()?.unix_timestamp;
+        
+        let attestation = Attestation {
+            score_bps,
+            defi_bps,
+            prediction_bps,
+            trust_level,
+            attested_at,
+        };
+        attestation.apply(
+            agent_key,
+            &mut ctx.accounts.agent_profile,
+            &mut ctx.accounts.trust_history,
+            &ctx.accounts.config,
+            now,
+        )
+    }
+
+    /// Attest several agents in one instruction.
+    /// 
+    /// For each entry, the agent's profile and trust history PDAs are
+    /// passed, in that order, via `remaining_accounts`. Every entry is
+    /// validated as in `attest_conviction`, and the batch fails as a whole
+    /// if any entry or account is bad.
+    pub fn attest_conviction_batch<'info>(
+        ctx: Context<'_, '_, 'info, 'info, AttestConvictionBatch<'info>>,
+        attestations: Vec<Attestation>,
+    ) -> Result<()> {
+        require!(!ctx.accounts.config.paused, AxiomError::ProgramPaused);
+        require!(
+            !attestations.is_empty() && attestations.len() <= MAX_ATTESTATION_BATCH,
+            AxiomError::InvalidBatchSize
+        );
+        require!(
+            ctx.remaining_accounts.len() == attestations.len() * 2,
+            AxiomError::BatchAccountMismatch
+        );
+        
+        let now = Clock::get()?.unix_timestamp;
+        
+        for (attestation, pair) in attestations.iter().zip(ctx.remaining_accounts.chunks(2)) {
+            let (profile_info, history_info) = (&pair[0], &pair[1]);
+            require!(
+                profile_info.is_writable && history_info.is_writable,
+                AxiomError::BatchAccountMismatch
+            );
+            
+            let mut profile = Account::<AgentProfile>::try_from(profile_info)?;
+            let expected = Pubkey::create_program_address(
+                &[AGENT_SEED, profile.seed_authority.as_ref(), &[profile.bump]],
+                ctx.program_id,
+            )
+            .map_err(|_| AxiomError::BatchAccountMismatch)?;
+            require_keys_eq!(profile_info.key(), expected, AxiomError::BatchAccountMismatch);
+            
+            let mut history = Account::<TrustHistory>::try_from(history_info)?;
+            let expected = Pubkey::create_program_address(
+                &[TRUST_HISTORY_SEED, profile_info.key.as_ref(), &[history.bump]],
+                ctx.program_id,
+            )
+            .map_err(|_| AxiomError::BatchAccountMismatch)?;
+            require_keys_eq!(history_info.key(), expected, AxiomError::BatchAccountMismatch);
+            
+            attestation.apply(
+                profile_info.key(),
+                &mut profile,
+                &mut history,
+                &ctx.accounts.config,
+                now,
+            )?;
+            
+            // Persist now so a repeated agent later in the batch sees this entry
+            profile.exit(ctx.program_id)?;
+            history.exit(ctx.program_id)?;
+        }
+        
+        Ok(())
+    }
+
+    /// Succeed only if the agent's stored trust level meets `required`.
+    /// `required` cannot be Unknown, which every agent would meet.
+    /// 
+    /// Instructions cannot return values, so composing programs gate on
+    /// this instruction succeeding. With the `cpi` feature enabled:
+    /// 
+    /// ```ignore
+    /// axiom::cpi::verify_trust(
+    ///     CpiContext::new(
+    ///         axiom_program.to_account_info(),
+    ///         axiom::cpi::accounts::VerifyTrust {
+    ///             agent_profile: agent_profile.to_account_info(),
+    ///         },
+    ///     ),
+    ///     axiom::TrustLevel::Medium.as_u8(),
+    /// )?; // the caller's instruction aborts here if trust is too low
+    /// ```
+    pub fn verify_trust(ctx: Context<VerifyTrust>, required: u8) -> Result<()> {
+        let required = TrustLevel::from_u8(required)
+            .filter(|level| *level != TrustLevel::Unknown)
+            .ok_or(AxiomError::InvalidTrustLevel)?;
+        let current = TrustLevel::from_u8(ctx.accounts.agent_profile.last_trust_level)
+            .ok_or(AxiomError::InvalidTrustLevel)?;
+        require!(current.meets(required), AxiomError::InsufficientTrust);
+        
+        Ok(())
+    }
+}
+
+// ─── Account Creation ──────────────────────────────────────────────────────
+
+/// Create a program-owned PDA of `space` bytes, paid by `payer`.
+/// 
+/// Mirrors Anchor's `init`: if the address already holds lamports (anyone
+/// can send some to a predictable PDA), it is topped up to rent exemption
+/// and then allocated and assigned, instead of failing `create_account`.
+fn create_pda_account<'info>(
+    payer: &AccountInfo<'info>,
+    target: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    space: usize,
+    signer_seeds: &[&[u8]],
+) -> Result<()> {
+    let rent = Rent::get()?.minimum_balance(space);
+    let current = target.lamports();
+    
+    if current == 0 {
+        return system_program::create_account(
+            CpiContext::new_with_signer(
+                system_program.clone(),
+                system_program::CreateAccount {
+                    from: payer.clone(),
+                    to: target.clone(),
+                },
+                &[signer_seeds],
+            ),
+            rent,
+            space as u64,
+            &crate::ID,
+        );
+    }
+    
+    let top_up = rent.saturating_sub(current);
+    if top_up > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                system_program.clone(),
+                system_program::Transfer {
+                    from: payer.clone(),
+                    to: target.clone(),
+                },
+            ),
+            top_up,
+        )?;
+    }
+    system_program::allocate(
+        CpiContext::new_with_signer(
+            system_program.clone(),
+            system_program::Allocate {
+                account_to_allocate: target.clone(),
+            },
+            &[signer_seeds],
+        ),
+        space as u64,
+    )?;
+    system_program::assign(
+        CpiContext::new_with_signer(
+            system_program.clone(),
+            system_program::Assign {
+                account_to_assign: target.clone(),
+            },
+            &[signer_seeds],
+        ),
+        &crate::ID,
+    )
+}
+
+// ─── Proof Domains ─────────────────────────────────────────────────────────
+
+/// Domain a reasoning proof applies to, stored as its discriminant in
+/// `ReasoningProof::domain`.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[repr(u8)]
+pub enum ProofDomain {
+    Defi = 0,
+    PredictionMarket = 1,
+    Governance = 2,
+    Other = 3,
+}
+
+impl ProofDomain {
+    /// Encode for storage in `ReasoningProof::domain`.
+    pub fn as_u8(self) -> u8 {
+        self as u8
+    }
+
+    /// Decode a stored domain, rejecting unknown values.
+    pub fn from_u8(value: u8) -> Option<Self> {
+        match value {
+            0 => Some(ProofDomain::Defi),
+            1 => Some(ProofDomain::PredictionMarket),
+            2 => Some(ProofDomain::Governance),
+            3 => Some(ProofDomain::Other),
+            _ => None,
+        }
+    }
+}
+
+// ─── Proof Batches ─────────────────────────────────────────────────────────
+
+/// Maximum number of proofs accepted by `store_reasoning_proofs`
+pub const MAX_PROOF_BATCH: usize = 16;
+
+/// A single entry in a `store_reasoning_proofs` batch.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct ProofInput {
+    /// Hash of the reasoning artifact
+    pub proof_hash: [u8; 32],
+    /// Domain the reasoning applies to (a `ProofDomain` discriminant)
+    pub domain: u8,
+}
+
+impl ProofInput {
+    pub fn new(proof_hash: [u8; 32], domain: ProofDomain) -> Self {
+        Self {
+            proof_hash,
+            domain: domain.as_u8(),
+        }
+    }
+}
+
+// ─── Trust Levels ──────────────────────────────────────────────────────────
+
+/// Basis point denominator (10000 = 100%)
+pub const MAX_BPS: u16 = 10_000;
+
+/// Minimum conviction for High trust (mirrors the off-chain 0.8 cutoff)
+pub const HIGH_TRUST_BPS: u16 = 8_000;
+
+/// Minimum conviction for Medium trust (mirrors the off-chain 0.4 cutoff)
+pub const MEDIUM_TRUST_BPS: u16 = 4_000;
+
+/// Trust level encoding stored in `AgentProfile::last_trust_level`.
+/// 
+/// Ordered Unknown < Low < Medium < High, so levels compare as gates.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[repr(u8)]
+pub enum TrustLevel {
+    /// No attestation recorded yet
+    Unknown = 0,
+    Low = 1,
+    Medium = 2,
+    High = 3,
+}
+
+impl TrustLevel {
+    /// Bucket a conviction score (basis points) into a trust level.
+    pub fn from_score_bps(score_bps: u16) -> Self {
+        if score_bps >= HIGH_TRUST_BPS {
+            TrustLevel::High
+        } else if score_bps >= MEDIUM_TRUST_BPS {
+            TrustLevel::Medium
+        } else {
+            TrustLevel::Low
+        }
+    }
+
+    /// Highest level a score may be attested at, given the config floor.
+    /// Scores below `min_trust_bps` justify no more than Low.
+    pub fn max_justified(score_bps: u16, min_trust_bps: u16) -> Self {
+        if score_bps < min_trust_bps {
+            TrustLevel::Low
+        } else {
+            TrustLevel::from_score_bps(score_bps)
+        }
+    }
+
+    /// Encode for storage in `AgentProfile::last_trust_level`.
+    pub fn as_u8(self) -> u8 {
+        self as u8
+    }
+
+    /// Decode a stored `last_trust_level`, rejecting unknown values.
+    pub fn from_u8(value: u8) -> Option<Self> {
+        match value {
+            0 => Some(TrustLevel::Unknown),
+            1 => Some(TrustLevel::Low),
+            2 => Some(TrustLevel::Medium),
+            3 => Some(TrustLevel::High),
+            _ => None,
+        }
+    }
+
+    /// Whether this level satisfies a required minimum level.
+    pub fn meets(&self, required: TrustLevel) -> bool {
+        *self >= required
+    }
+}
+
+// ─── Attestations ──────────────────────────────────────────────────────────
+
+/// Maximum number of agents accepted by `attest_conviction_batch`
+pub const MAX_ATTESTATION_BATCH: usize = 8;
+
+/// A conviction attestation as submitted by the oracle.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Attestation {
+    /// Overall conviction score in basis points
+    pub score_bps: u16,
+    /// DeFi conviction in basis points
+    pub defi_bps: u16,
+    /// Prediction-market conviction in basis points
+    pub prediction_bps: u16,
+    /// Claimed trust level (`TrustLevel` encoding, not Unknown)
+    pub trust_level: u8,
+    /// Oracle source timestamp of the score
+    pub attested_at: i64,
+}
+
+impl Attestation {
+    /// Validate against the config and the profile's last attestation,
+    /// then record it on the profile and its history.
+    fn apply(
+        &self,
+        agent: Pubkey,
+        profile: &mut AgentProfile,
+        history: &mut TrustHistory,
+        config: &Config,
+        now: i64,
+    ) -> Result<()> {
+        require!(self.score_bps <= MAX_BPS, AxiomError::InvalidBasisPoints);
+        require!(self.defi_bps <= MAX_BPS, AxiomError::InvalidBasisPoints);
+        require!(self.prediction_bps <= MAX_BPS, AxiomError::InvalidBasisPoints);
+        
+        let claimed = TrustLevel::from_u8(self.trust_level)
+            .filter(|level| *level != TrustLevel::Unknown)
+            .ok_or(AxiomError::InvalidTrustLevel)?;
+        let justified = TrustLevel::max_justified(self.score_bps, config.min_trust_bps);
+        require!(justified.meets(claimed), AxiomError::TrustLevelNotJustified);
+        // High trust must also be backed by enough stake
+        let level = if claimed == TrustLevel::High
+            && !config.stake_backs_high(profile.staked_lamports)
+        {
+            TrustLevel::Medium
+        } else {
+            claimed
+        };
+        
+        require!(self.attested_at <= now, AxiomError::AttestationInFuture);
+        require!(self.attested_at > profile.attested_at, AxiomError::StaleAttestation);
+        
+        profile.conviction_bps = self.score_bps;
+        profile.last_defi_bps = self.defi_bps;
+        profile.last_prediction_bps = self.prediction_bps;
+        profile.last_trust_level = level.as_u8();
+        profile.attested_at = self.attested_at;
+        
+        history.push(TrustHistoryEntry {
+            level: level.as_u8(),
+            score_bps: self.score_bps,
+            timestamp: self.attested_at,
+        });
+        
+        emit!(ConvictionAttested {
+            agent,
+            score_bps: self.score_bps,
+            defi_bps: self.defi_bps,
+            prediction_bps: self.prediction_bps,
+            trust_level: level.as_u8(),
+            timestamp: self.attested_at,
+        });
+        
+        Ok(())
+    }
+}
+
+// ─── Account Structs ───────────────────────────────────────────────────────
+
+/// Length of the Anchor account discriminator prefixed to every account
+pub const DISCRIMINATOR_LEN: usize = 8;
+
+/// Full on-chain size of an account of type `T`: discriminator plus its
+/// `InitSpace`. Use for `space =` constraints and rent calculations.
+pub const fn space<T: Space>() -> usize {
+    DISCRIMINATOR_LEN + T::INIT_SPACE
+}
+
+/// Current `AgentProfile` layout version
+pub const AGENT_PROFILE_VERSION: u8 = 2;
+
+#[account]
+#[derive(InitSpace)]
+pub struct AgentProfile {
+    /// Layout version (see `AGENT_PROFILE_VERSION`); always the first byte
+    /// after the discriminator so future migrations can branch on it
+    pub version: u8,
+    /// The agent's authority (wallet that controls this profile)
+    pub authority: Pubkey,
+    /// Display name (max 64 chars)
+    #[max_len(64)]
+    pub name: String,
+    /// Total reasoning commitments published
+    pub total_commitments: u64,
+    /// Total commitments that have been revealed
+    pub total_verified: u64,
+    /// Accountability score in basis points (0-10000 = 0%-100%)
+    pub accountability_score: u16,
+    /// When the agent registered
+    pub created_at: i64,
+    /// PDA bump
+    pub bump: u8,
+    /// Most recent trust level recorded for this agent (0 = not yet attested)
+    pub last_trust_level: u8,
+    /// Latest attested conviction score in basis points (0-10000)
+    pub conviction_bps: u16,
+    /// Oracle source timestamp of the latest attestation (0 = never)
+    pub attested_at: i64,
+    /// Next expected proof submission nonce (replay protection)
+    pub nonce: u64,
+    /// Hot wallet allowed to submit proofs on the agent's behalf
+    pub delegate: Option<Pubkey>,
+    /// Total reasoning proofs stored
+    pub proof_count: u64,
+    /// When the most recent proof was stored (0 = never)
+    pub last_proof_at: i64,
+    /// Wallet the PDA was derived from at registration. Unlike
+    /// `authority`, this never changes on ownership transfer.
+    pub seed_authority: Pubkey,
+    /// Lamports currently staked across all of the agent's proofs
+    pub staked_lamports: u64,
+    /// Latest attested DeFi conviction in basis points
+    pub last_defi_bps: u16,
+    /// Latest attested prediction-market conviction in basis points
+    pub last_prediction_bps: u16,
+}
+
+impl AgentProfile {
+    /// Account size, including the discriminator
+    pub const LEN: usize = space::<Self>();
+
+    /// Fresh profile for a newly registered agent.
+    pub fn new(authority: Pubkey, name: String, bump: u8, now: i64) -> Result<Self> {
+        require!(name.len() <= 64, AxiomError::NameTooLong);
+        require!(!name.is_empty(), AxiomError::NameEmpty);
+        
+        Ok(Self {
+            version: AGENT_PROFILE_VERSION,
+            authority,
+            name,
+            total_commitments: 0,
+            total_verified: 0,
+            accountability_score: 10000, // Start at 100.00% (basis points)
+            created_at: now,
+            bump,
+            last_trust_level: TrustLevel::Unknown.as_u8(),
+            conviction_bps: 0,
+            attested_at: 0,
+            nonce: 0,
+            delegate: None,
+            proof_count: 0,
+            last_proof_at: 0,
+            seed_authority: authority,
+            staked_lamports: 0,
+            last_defi_bps: 0,
+            last_prediction_bps: 0,
+        })
+    }
+
+    /// Decode `last_trust_level`, or `None` for an unrecognized encoding.
+    pub fn trust_level(&self) -> Option<TrustLevel> {
+        TrustLevel::from_u8(self.last_trust_level)
+    }
+
+    /// Whether `signer` may submit proofs for this agent.
+    pub fn can_submit(&self, signer: &Pubkey) -> bool {
+        *signer == self.authority || self.delegate == Some(*signer)
+    }
+
+    /// Record a submission of `count` proofs at `now`, consuming `nonce`,
+    /// which must be the next expected one.
+    pub fn record_proofs(&mut self, nonce: u64, count: u64, now: i64) -> Result<()> {
+        require!(nonce == self.nonce, AxiomError::InvalidNonce);
+        self.nonce = self.nonce.checked_add(1)
+            .ok_or(AxiomError::Overflow)?;
+        self.proof_count = self.proof_count.checked_add(count)
+            .ok_or(AxiomError::Overflow)?;
+        self.last_proof_at = now;
+        Ok(())
+    }
+
+    /// Latest attested conviction for a proof domain, or `None` for
+    /// domains without a dedicated score.
+    pub fn domain_bps(&self, domain: ProofDomain) -> Option<u16> {
+        match domain {
+            ProofDomain::Defi => Some(self.last_defi_bps),
+            ProofDomain::PredictionMarket => Some(self.last_prediction_bps),
+            ProofDomain::Governance | ProofDomain::Other => None,
+        }
+    }
+
+    /// Drop a High trust level to Medium once the agent's stake no longer
+    /// backs it. Returns whether the level changed.
+    pub fn cap_unbacked_high(&mut self, config: &Config) -> bool {
+        if self.trust_level() == Some(TrustLevel::High)
+            && !config.stake_backs_high(self.staked_lamports)
+        {
+            self.last_trust_level = TrustLevel::Medium.as_u8();
+            true
+        } else {
+            false
+        }
+    }
+}
+
+/// Legacy (v1) `AgentProfile` layout, predating the version byte.
+/// Only used by `migrate_agent_profile` to read old accounts.
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace)]
+pub struct AgentProfileV1 {
+    pub authority: Pubkey,
+    #[max_len(64)]
+    pub name: String,
+    pub total_commitments: u64,
+    pub total_verified: u64,
+    pub accountability_score: u16,
+    pub created_at: i64,
+    pub bump: u8,
+}
+
+impl AgentProfileV1 {
+    /// Legacy account size, including the discriminator
+    pub const LEN: usize = space::<Self>();
+
+    /// Decode raw `AgentProfile` account data that is still in the legacy
+    /// layout, which is recognized by its size.
+    pub fn from_account_data(data: &[u8]) -> Result<Self> {
+        require!(
+            data.len() >= DISCRIMINATOR_LEN && data[..DISCRIMINATOR_LEN] == *AgentProfile::DISCRIMINATOR,
+            AxiomError::AccountVersionMismatch
+        );
+        require!(data.len() == Self::LEN, AxiomError::AlreadyMigrated);
+        
+        Ok(Self::deserialize(&mut &data[DISCRIMINATOR_LEN..])?)
+    }
+
+    /// Current-layout profile with every legacy field preserved and new
+    /// fields defaulted.
+    pub fn upgrade(self) -> AgentProfile {
+        AgentProfile {
+            version: AGENT_PROFILE_VERSION,
+            authority: self.authority,
+            name: self.name,
+            total_commitments: self.total_commitments,
+            total_verified: self.total_verified,
+            accountability_score: self.accountability_score,
+            created_at: self.created_at,
+            bump: self.bump,
+            last_trust_level: TrustLevel::Unknown.as_u8(),
+            conviction_bps: 0,
+            attested_at: 0,
+            nonce: 0,
+            delegate: None,
+            proof_count: 0,
+            last_proof_at: 0,
+            seed_authority: self.authority,
+            staked_lamports: 0,
+            last_defi_bps: 0,
+            last_prediction_bps: 0,
+        }
+    }
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct ReasoningCommitment {
+    /// The agent profile this commitment belongs to
+    pub agent: Pubkey,
+    /// The authority that created this commitment
+    pub authority: Pubkey,
+    /// SHA-256 hash of the full reasoning trace
+    pub commitment_hash: [u8; 32],
+    /// Type of action (e.g., "trade", "audit", "rebalance")
+    #[max_len(32)]
+    pub action_type: String,
+    /// Confidence score (0-100)
+    pub confidence: u8,
+    /// Unix timestamp when committed
+    pub timestamp: i64,
+    /// Whether the full reasoning has been revealed
+    pub revealed: bool,
+    /// URI to the full reasoning (IPFS, Arweave, etc.)
+    #[max_len(256)]
+    pub reasoning_uri: String,
+    /// Nonce for unique PDA derivation (allows multiple commitments)
+    pub nonce: u64,
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl ReasoningCommitment {
+    /// Account size, including the discriminator
+    pub const LEN: usize = space::<Self>();
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct ReasoningProof {
+    /// The agent profile this proof belongs to
+    pub agent: Pubkey,
+    /// Hash of the reasoning artifact
+    pub proof_hash: [u8; 32],
+    /// Domain the reasoning applies to (a `ProofDomain` discriminant)
+    pub domain: u8,
+    /// Unix timestamp when stored
+    pub created_at: i64,
+    /// Whether the proof has been verified
+    pub verified: bool,
+    /// Unix timestamp after which the proof can no longer be verified
+    pub expires_at: i64,
+    /// Unix timestamp before which the proof can still be challenged
+    pub verifiable_after: i64,
+    /// Whether the oracle has disputed the proof (blocks verification)
+    pub disputed: bool,
+    /// Stake account backing this proof, if any
+    pub stake: Option<Pubkey>,
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl ReasoningProof {
+    /// Account size, including the discriminator
+    pub const LEN: usize = space::<Self>();
+
+    /// Decode `domain`, or `None` for an unrecognized encoding.
+    pub fn proof_domain(&self) -> Option<ProofDomain> {
+        ProofDomain::from_u8(self.domain)
+    }
+
+    /// Check that the proof can be verified at `now`: not disputed, past
+    /// its challenge window and not yet expired.
+    pub fn check_verifiable(&self, now: i64) -> Result<()> {
+        require!(!self.disputed, AxiomError::ProofDisputed);
+        require!(now >= self.verifiable_after, AxiomError::ChallengeWindowOpen);
+        require!(now < self.expires_at, AxiomError::ProofExpired);
+        Ok(())
+    }
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct StakeAccount {
+    /// The agent profile that staked
+    pub agent: Pubkey,
+    /// The reasoning proof the stake backs
+    pub proof: Pubkey,
+    /// Staked lamports (excluding rent)
+    pub amount: u64,
+    /// Unix timestamp before which the stake cannot be withdrawn
+    pub locked_until: i64,
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl StakeAccount {
+    /// Account size, including the discriminator
+    pub const LEN: usize = space::<Self>();
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct Treasury {
+    /// Lamports received from slashes since creation
+    pub total_collected: u64,
+    /// Lamports withdrawn by the admin since creation
+    pub total_withdrawn: u64,
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl Treasury {
+    /// Account size, including the discriminator
+    pub const LEN: usize = space::<Self>();
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct Config {
+    /// Authority allowed to update the config
+    pub admin: Pubkey,
+    /// Oracle allowed to attest conviction scores
+    pub oracle: Pubkey,
+    /// Whether state-changing instructions are frozen
+    pub paused: bool,
+    /// Conviction floor (bps) below which attestations are capped at Low
+    pub min_trust_bps: u16,
+    /// Seconds a new proof stays open to challenges before verification
+    pub challenge_window_secs: i64,
+    /// Lamports an agent must have staked to be attested at High trust
+    pub min_stake_for_high: u64,
+    /// DeFi conviction (bps) required to verify DeFi proofs
+    pub min_defi_bps: u16,
+    /// Prediction-market conviction (bps) required to verify such proofs
+    pub min_prediction_bps: u16,
+    /// Minimum seconds a new stake stays locked, whatever the proof's TTL
+    pub min_stake_lock_secs: i64,
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl Config {
+    /// Account size, including the discriminator
+    pub const LEN: usize = space::<Self>();
+
+    /// Domain conviction (bps) an agent needs to verify a proof in
+    /// `domain`; 0 means no requirement.
+    pub fn min_domain_bps(&self, domain: ProofDomain) -> u16 {
+        match domain {
+            ProofDomain::Defi => self.min_defi_bps,
+            ProofDomain::PredictionMarket => self.min_prediction_bps,
+            ProofDomain::Governance | ProofDomain::Other => 0,
+        }
+    }
+
+    /// Whether `staked_lamports` is enough stake to hold High trust.
+    pub fn stake_backs_high(&self, staked_lamports: u64) -> bool {
+        staked_lamports >= self.min_stake_for_high
+    }
+}
+
+/// Number of attestations retained by each `TrustHistory`.
+pub const TRUST_HISTORY_CAPACITY: usize = 16;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq, InitSpace)]
+pub struct TrustHistoryEntry {
+    /// Attested trust level (`TrustLevel` encoding)
+    pub level: u8,
+    /// Attested conviction score in basis points
+    pub score_bps: u16,
+    /// Oracle source timestamp of the attestation
+    pub timestamp: i64,
+}
+
+/// Ring buffer of an agent's most recent attestations. Once full, each
+/// new attestation overwrites the oldest.
+#[account]
+#[derive(InitSpace)]
+pub struct TrustHistory {
+    /// Agent profile this history belongs to
+    pub agent: Pubkey,
+    /// Entry slots; only the `len` slots ending just before `head` are live
+    pub entries: [TrustHistoryEntry; TRUST_HISTORY_CAPACITY],
+    /// Slot the next entry is written to
+    pub head: u8,
+    /// Number of live entries (at most `TRUST_HISTORY_CAPACITY`)
+    pub len: u8,
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl TrustHistory {
+    /// Account size, including the discriminator
+    pub const LEN: usize = space::<Self>();
+
+    /// Empty history for an agent profile.
+    pub fn new(agent: Pubkey, bump: u8) -> Self {
+        Self {
+            agent,
+            entries: [TrustHistoryEntry::default(); TRUST_HISTORY_CAPACITY],
+            head: 0,
+            len: 0,
+            bump,
+        }
+    }
+
+    /// Append an entry, overwriting the oldest one when full.
+    pub fn push(&mut self, entry: TrustHistoryEntry) {
+        self.entries[self.head as usize] = entry;
+        self.head = ((self.head as usize + 1) % TRUST_HISTORY_CAPACITY) as u8;
+        if (self.len as usize) < TRUST_HISTORY_CAPACITY {
+            self.len += 1;
+        }
+    }
+
+    /// Live entries, oldest first.
+    pub fn entries_chronological(&self) -> Vec<TrustHistoryEntry> {
+        let start = self.head as usize + TRUST_HISTORY_CAPACITY - self.len as usize;
+        (0..self.len as usize)
+            .map(|i| self.entries[(start + i) % TRUST_HISTORY_CAPACITY])
+            .collect()
+    }
+}
+
+// ─── Instruction Contexts ──────────────────────────────────────────────────
+
+#[derive(Accounts)]
+#[instruction(name: String)]
+pub struct RegisterAgent<'info> {
+    /// CHECK: Created in the handler, so an existing profile fails the
+    /// emptiness check with `AgentAlreadyRegistered` instead of the system
+    /// program's generic "already in use" error.
+    #[account(
+        mut,
+        seeds = [AGENT_SEED, authority.key().as_ref()],
+        bump,
+        constraint = agent_profile.data_is_empty() @ AxiomError::AgentAlreadyRegistered
+    )]
+    pub agent_profile: UncheckedAccount<'info>,
+    
+    #[account(
+        init,
+        payer = authority,
+        space = TrustHistory::LEN,
+        seeds = [TRUST_HISTORY_SEED, agent_profile.key().as_ref()],
+        bump
+    )]
+    pub trust_history: Account<'info, TrustHistory>,
+    
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    #[account(seeds = [CONFIG_SEED], bump = config.bump)]
+    pub config: Account<'info, Config>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(name: String)]
+pub struct RegisterAgentWithAttestation<'info> {
+    /// CHECK: Created in the handler, so an existing profile fails the
+    /// emptiness check with `AgentAlreadyRegistered` instead of the system
+    /// program's generic "already in use" error.
+    #[account(
+        mut,
+        seeds = [AGENT_SEED, authority.key().as_ref()],
+        bump,
+        constraint = agent_profile.data_is_empty() @ AxiomError::AgentAlreadyRegistered
+    )]
+    pub agent_profile: UncheckedAccount<'info>,
+    
+    #[account(
+        init,
+        payer = authority,
+        space = TrustHistory::LEN,
+        seeds = [TRUST_HISTORY_SEED, agent_profile.key().as_ref()],
+        bump
+    )]
+    pub trust_history: Account<'info, TrustHistory>,
+    
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+        has_one = oracle @ AxiomError::Unauthorized
+    )]
+    pub config: Account<'info, Config>,
+    
+    pub oracle: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct MigrateAgentProfile<'info> {
+    /// CHECK: Legacy layouts cannot be loaded as `Account<AgentProfile>`;
+    /// the PDA seeds and owner are checked here and the discriminator,
+    /// size and authority in the handler.
+    #[account(
+        mut,
+        seeds = [AGENT_SEED, authority.key().as_ref()],
+        bump,
+        owner = crate::ID
+    )]
+    pub agent_profile: UncheckedAccount<'info>,
+    
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateAgent<'info> {
+    #[account(
+        mut,
+        seeds = [AGENT_SEED, agent_profile.seed_authority.as_ref()],
+        bump = agent_profile.bump,
+        has_one = authority @ AxiomError::Unauthorized
+    )]
+    pub agent_profile: Account<'info, AgentProfile>,
+    
+    pub authority: Signer<'info>,
+    
+    #[account(seeds = [CONFIG_SEED], bump = config.bump)]
+    pub config: Account<'info, Config>,
+}
+
+#[derive(Accounts)]
+#[instruction(commitment_hash: [u8; 32], action_type: String, confidence: u8, nonce: u64)]
+pub struct CommitReasoning<'info> {
+    #[account(
+        init,
+        payer = authority,
+        space = ReasoningCommitment::LEN,
+        seeds = [
+            COMMITMENT_SEED,
+            agent_profile.key().as_ref(),
+            &nonce.to_le_bytes()
+        ],
+        bump
+    )]
+    pub commitment: Account<'info, ReasoningCommitment>,
+    
+    #[account(
+        mut,
+        seeds = [AGENT_SEED, agent_profile.seed_authority.as_ref()],
+        bump = agent_profile.bump,
+        has_one = authority
+    )]
+    pub agent_profile: Account<'info, AgentProfile>,
+    
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct RevealReasoning<'info> {
+    #[account(
+        mut,
+        constraint = commitment.agent == agent_profile.key() @ AxiomError::AgentMismatch
+    )]
+    pub commitment: Account<'info, ReasoningCommitment>,
+    
+    #[account(
+        mut,
+        seeds = [AGENT_SEED, agent_profile.seed_authority.as_ref()],
+        bump = agent_profile.bump,
+        has_one = authority
+    )]
+    pub agent_profile: Account<'info, AgentProfile>,
+    
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(proof_hash: [u8; 32])]
+pub struct StoreReasoningProof<'info> {
+    #[account(
+        init,
+        payer = submitter,
+        space = ReasoningProof::LEN,
+        seeds = [
+            PROOF_SEED,
+            agent_profile.key().as_ref(),
+            &proof_hash
+        ],
+        bump
+    )]
+    pub proof: Account<'info, ReasoningProof>,
+    
+    #[account(
+        mut,
+        seeds = [AGENT_SEED, agent_profile.seed_authority.as_ref()],
+        bump = agent_profile.bump,
+        constraint = agent_profile.can_submit(&submitter.key()) @ AxiomError::NotAuthorityOrDelegate
+    )]
+    pub agent_profile: Account<'info, AgentProfile>,
+    
+    /// The agent's authority or its delegate
+    #[account(mut)]
+    pub submitter: Signer<'info>,
+    
+    #[account(seeds = [CONFIG_SEED], bump = config.bump)]
+    pub config: Account<'info, Config>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct StoreReasoningProofs<'info> {
+    #[account(
+        mut,
+        seeds = [AGENT_SEED, agent_profile.seed_authority.as_ref()],
+        bump = agent_profile.bump,
+        constraint = agent_profile.can_submit(&submitter.key()) @ AxiomError::NotAuthorityOrDelegate
+    )]
+    pub agent_profile: Account<'info, AgentProfile>,
+    
+    /// The agent's authority or its delegate
+    #[account(mut)]
+    pub submitter: Signer<'info>,
+    
+    #[account(seeds = [CONFIG_SEED], bump = config.bump)]
+    pub config: Account<'info, Config>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct VerifyProof<'info> {
+    #[account(
+        mut,
+        constraint = proof.agent == agent_profile.key() @ AxiomError::AgentMismatch
+    )]
+    pub proof: Account<'info, ReasoningProof>,
+    
+    #[account(
+        seeds = [AGENT_SEED, agent_profile.seed_authority.as_ref()],
+        bump = agent_profile.bump
+    )]
+    pub agent_profile: Account<'info, AgentProfile>,
+    
+    #[account(seeds = [CONFIG_SEED], bump = config.bump)]
+    pub config: Account<'info, Config>,
+}
+
+#[derive(Accounts)]
+pub struct ChallengeProof<'info> {
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+        has_one = oracle @ AxiomError::Unauthorized
+    )]
+    pub config: Account<'info, Config>,
+    
+    #[account(mut)]
+    pub proof: Account<'info, ReasoningProof>,
+    
+    pub oracle: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct CloseProof<'info> {
+    #[account(
+        mut,
+        close = recipient,
+        constraint = proof.agent == agent_profile.key() @ AxiomError::Unauthorized,
+        constraint = proof.stake.is_none() @ AxiomError::ProofHasStake,
+        constraint = !proof.disputed @ AxiomError::ProofDisputed
+    )]
+    pub proof: Account<'info, ReasoningProof>,
+    
+    #[account(
+        seeds = [AGENT_SEED, agent_profile.seed_authority.as_ref()],
+        bump = agent_profile.bump,
+        has_one = authority @ AxiomError::Unauthorized
+    )]
+    pub agent_profile: Account<'info, AgentProfile>,
+    
+    pub authority: Signer<'info>,
+    
+    /// CHECK: Any account may receive the reclaimed rent
+    #[account(mut)]
+    pub recipient: UncheckedAccount<'info>,
+}
+
+#[derive(Accounts)]
+pub struct StakeOnProof<'info> {
+    #[account(
+        init,
+        payer = authority,
+        space = StakeAccount::LEN,
+        seeds = [STAKE_SEED, proof.key().as_ref()],
+        bump
+    )]
+    pub stake: Account<'info, StakeAccount>,
+    
+    #[account(
+        mut,
+        constraint = proof.agent == agent_profile.key() @ AxiomError::Unauthorized
+    )]
+    pub proof: Account<'info, ReasoningProof>,
+    
+    #[account(
+        mut,
+        seeds = [AGENT_SEED, agent_profile.seed_authority.as_ref()],
+        bump = agent_profile.bump,
+        has_one = authority @ AxiomError::Unauthorized
+    )]
+    pub agent_profile: Account<'info, AgentProfile>,
+    
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    #[account(seeds = [CONFIG_SEED], bump = config.bump)]
+    pub config: Account<'info, Config>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct Unstake<'info> {
+    #[account(
+        mut,
+        close = authority,
+        seeds = [STAKE_SEED, proof.key().as_ref()],
+        bump = stake.bump,
+        has_one = proof,
+        constraint = stake.agent == agent_profile.key() @ AxiomError::Unauthorized
+    )]
+    pub stake: Account<'info, StakeAccount>,
+    
+    #[account(mut)]
+    pub proof: Account<'info, ReasoningProof>,
+    
+    #[account(
+        mut,
+        seeds = [AGENT_SEED, agent_profile.seed_authority.as_ref()],
+        bump = agent_profile.bump,
+        has_one = authority @ AxiomError::Unauthorized
+    )]
+    pub agent_profile: Account<'info, AgentProfile>,
+    
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    #[account(seeds = [CONFIG_SEED], bump = config.bump)]
+    pub config: Account<'info, Config>,
+}
+
+#[derive(Accounts)]
+pub struct Slash<'info> {
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+        has_one = oracle @ AxiomError::Unauthorized
+    )]
+    pub config: Account<'info, Config>,
+    
+    #[account(
+        mut,
+        seeds = [STAKE_SEED, proof.key().as_ref()],
+        bump = stake.bump,
+        has_one = proof
+    )]
+    pub stake: Account<'info, StakeAccount>,
+    
+    #[account(mut)]
+    pub proof: Account<'info, ReasoningProof>,
+    
+    #[account(
+        mut,
+        seeds = [AGENT_SEED, agent_profile.seed_authority.as_ref()],
+        bump = agent_profile.bump,
+        constraint = stake.agent == agent_profile.key() @ AxiomError::AgentMismatch
+    )]
+    pub agent_profile: Account<'info, AgentProfile>,
+    
+    #[account(
+        mut,
+        seeds = [TREASURY_SEED],
+        bump = treasury.bump
+    )]
+    pub treasury: Account<'info, Treasury>,
+    
+    pub oracle: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct InitializeTreasury<'info> {
+    #[account(
+        init,
+        payer = admin,
+        space = Treasury::LEN,
+        seeds = [TREASURY_SEED],
+        bump
+    )]
+    pub treasury: Account<'info, Treasury>,
+    
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+        has_one = admin @ AxiomError::Unauthorized
+    )]
+    pub config: Account<'info, Config>,
+    
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct WithdrawTreasury<'info> {
+    #[account(
+        mut,
+        seeds = [TREASURY_SEED],
+        bump = treasury.bump
+    )]
+    pub treasury: Account<'info, Treasury>,
+    
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+        has_one = admin @ AxiomError::Unauthorized
+    )]
+    pub config: Account<'info, Config>,
+    
+    pub admin: Signer<'info>,
+    
+    /// CHECK: Any account may receive withdrawn lamports
+    #[account(mut)]
+    pub recipient: UncheckedAccount<'info>,
+}
+
+#[derive(Accounts)]
+pub struct InitializeConfig<'info> {
+    #[account(
+        init,
+        payer = admin,
+        space = Config::LEN,
+        seeds = [CONFIG_SEED],
+        bump
+    )]
+    pub config: Account<'info, Config>,
+    
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    
+    #[account(constraint = program.programdata_address()? == Some(program_data.key()))]
+    pub program: Program<'info, crate::program::Axiom>,
+    
+    #[account(
+        constraint = program_data.upgrade_authority_address == Some(admin.key())
+            @ AxiomError::Unauthorized
+    )]
+    pub program_data: Account<'info, ProgramData>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateConfig<'info> {
+    #[account(
+        mut,
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+        has_one = admin @ AxiomError::Unauthorized
+    )]
+    pub config: Account<'info, Config>,
+    
+    pub admin: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct AttestConviction<'info> {
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+        has_one = oracle @ AxiomError::Unauthorized
+    )]
+    pub config: Account<'info, Config>,
+    
+    #[account(
+        mut,
+        seeds = [AGENT_SEED, agent_profile.seed_authority.as_ref()],
+        bump = agent_profile.bump
+    )]
+    pub agent_profile: Account<'info, AgentProfile>,
+    
+    #[account(
+        mut,
+        seeds = [TRUST_HISTORY_SEED, agent_profile.key().as_ref()],
+        bump = trust_history.bump
+    )]
+    pub trust_history: Account<'info, TrustHistory>,
+    
+    pub oracle: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct AttestConvictionBatch<'info> {
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+        has_one = oracle @ AxiomError::Unauthorized
+    )]
+    pub config: Account<'info, Config>,
+    
+    pub oracle: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct InitializeTrustHistory<'info> {
+    #[account(
+        init,
+        payer = payer,
+        space = TrustHistory::LEN,
+        seeds = [TRUST_HISTORY_SEED, agent_profile.key().as_ref()],
+        bump
+    )]
+    pub trust_history: Account<'info, TrustHistory>,
+    
+    #[account(
+        seeds = [AGENT_SEED, agent_profile.seed_authority.as_ref()],
+        bump = agent_profile.bump
+    )]
+    pub agent_profile: Account<'info, AgentProfile>,
+    
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct VerifyTrust<'info> {
+    #[account(
+        seeds = [AGENT_SEED, agent_profile.seed_authority.as_ref()],
+        bump = agent_profile.bump
+    )]
+    pub agent_profile: Account<'info, AgentProfile>,
+}
+
+// ─── Events ────────────────────────────────────────────────────────────────
+
+#[event]
+pub struct AgentRegistered {
+    /// Authority the agent profile PDA is derived from
+    pub authority: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AgentOwnershipTransferred {
+    pub agent: Pubkey,
+    pub previous: Pubkey,
+    pub new_owner: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct DelegateUpdated {
+    pub agent: Pubkey,
+    /// New delegate, or None when revoked
+    pub delegate: Option<Pubkey>,
+}
+
+#[event]
+pub struct ReasoningCommitted {
     pub agent: Pubkey,
     pub commitment: Pubkey,
     pub action_type: String,
@@ -253,8 +2169,78 @@ pub struct ReasoningRevealed {
     pub timestamp: i64,
 }
 
+#[event]
+pub struct ProofStored {
+    /// Agent profile the proof PDA is derived from
+    pub agent: Pubkey,
+    pub proof_hash: [u8; 32],
+    pub domain: u8,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ProofVerified {
+    pub agent: Pubkey,
+    pub proof: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ProofChallenged {
+    pub agent: Pubkey,
+    pub proof: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ProofStaked {
+    pub agent: Pubkey,
+    pub proof: Pubkey,
+    pub amount: u64,
+    pub locked_until: i64,
+}
+
+#[event]
+pub struct ProofUnstaked {
+    pub agent: Pubkey,
+    pub proof: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct ProofSlashed {
+    pub agent: Pubkey,
+    pub proof: Pubkey,
+    pub bps: u16,
+    /// Lamports moved to the treasury
+    pub amount: u64,
+    /// Stake left after the slash
+    pub remaining: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ConvictionAttested {
+    pub agent: Pubkey,
+    pub score_bps: u16,
+    pub defi_bps: u16,
+    pub prediction_bps: u16,
+    pub trust_level: u8,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct TrustLevelCapped {
+    pub agent: Pubkey,
+    /// Level after the cap
+    pub trust_level: u8,
+    pub timestamp: i64,
+}
+
 // ─── Errors ────────────────────────────────────────────────────────────────
 
+/// Error codes are assigned by position (6000 + index) and are part of the
+/// client ABI: only ever append new variants, never reorder or remove.
 #[error_code]
 pub enum AxiomError {
     #[msg("Agent name must be 64 characters or less")]
@@ -275,4 +2261,474 @@ pub enum AxiomError {
     AgentMismatch,
     #[msg("Arithmetic overflow")]
     Overflow,
+    #[msg("Proof hash cannot be all zeros")]
+    ProofHashEmpty,
+    #[msg("Agent is already registered")]
+    AgentAlreadyRegistered,
+    #[msg("Signer is not authorized for this action")]
+    Unauthorized,
+    #[msg("Proof has expired")]
+    ProofExpired,
+    #[msg("Trust level is not valid")]
+    InvalidTrustLevel,
+    #[msg("Basis points must be between 0 and 10000")]
+    InvalidBasisPoints,
+    #[msg("Program is paused")]
+    ProgramPaused,
+    #[msg("Proof TTL must be positive")]
+    InvalidTtl,
+    #[msg("Stake amount must be greater than zero")]
+    InvalidStakeAmount,
+    #[msg("Stake is still locked")]
+    StakeLocked,
+    #[msg("Proof has an active stake")]
+    ProofHasStake,
+    #[msg("Batch size is out of range")]
+    InvalidBatchSize,
+    #[msg("Batch accounts do not match the batch entries")]
+    BatchAccountMismatch,
+    #[msg("Duplicate proof hash in batch")]
+    DuplicateProofHash,
+    #[msg("Agent profile is already at the current version")]
+    AlreadyMigrated,
+    #[msg("Account is not a recognized agent profile layout")]
+    AccountVersionMismatch,
+    #[msg("Nonce does not match the next expected nonce")]
+    InvalidNonce,
+    #[msg("Agent trust level is below the required level")]
+    InsufficientTrust,
+    #[msg("Signer is neither the agent authority nor its current delegate")]
+    NotAuthorityOrDelegate,
+    #[msg("Withdrawal exceeds the treasury's available balance")]
+    InsufficientTreasuryFunds,
+    #[msg("Proof domain is not recognized")]
+    InvalidDomain,
+    #[msg("Trust level is higher than the conviction score justifies")]
+    TrustLevelNotJustified,
+    #[msg("Attestation is not newer than the stored one")]
+    StaleAttestation,
+    #[msg("Attestation timestamp is in the future")]
+    AttestationInFuture,
+    #[msg("Proof is still within its challenge window")]
+    ChallengeWindowOpen,
+    #[msg("Proof has been disputed and cannot be verified")]
+    ProofDisputed,
+    #[msg("Proof has already been verified")]
+    ProofAlreadyVerified,
+    #[msg("Challenge window must not be negative")]
+    InvalidChallengeWindow,
+    #[msg("Agent's conviction in the proof's domain is below the required minimum")]
+    InsufficientDomainConviction,
+    #[msg("Minimum stake lock must not be negative")]
+    InvalidStakeLock,
+    #[msg("New owner must not be the default public key")]
+    InvalidNewOwner,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// All-zero value of a borsh type: empty strings, `None`s and zero
+    /// numbers. Fixtures start from this and set only the fields a test
+    /// cares about.
+    pub(crate) fn zeroed<T: AnchorDeserialize>() -> T {
+        T::deserialize(&mut &[0u8; 1024][..]).unwrap()
+    }
+
+    #[test]
+    fn trust_levels_are_ordered() {
+        let levels = [
+            TrustLevel::Unknown,
+            TrustLevel::Low,
+            TrustLevel::Medium,
+            TrustLevel::High,
+        ];
+        for (i, level) in levels.iter().enumerate() {
+            for (j, other) in levels.iter().enumerate() {
+                assert_eq!(level.meets(*other), i >= j, "{level:?} meets {other:?}");
+                assert_eq!(level.cmp(other), i.cmp(&j));
+            }
+        }
+    }
+
+    #[test]
+    fn trust_levels_round_trip_through_u8() {
+        for level in [
+            TrustLevel::Unknown,
+            TrustLevel::Low,
+            TrustLevel::Medium,
+            TrustLevel::High,
+        ] {
+            assert_eq!(TrustLevel::from_u8(level.as_u8()), Some(level));
+        }
+        assert_eq!(TrustLevel::High.as_u8(), 3);
+        for value in 4..=u8::MAX {
+            assert_eq!(TrustLevel::from_u8(value), None);
+        }
+    }
+
+    #[test]
+    fn claims_are_capped_by_the_justified_level() {
+        assert_eq!(
+            TrustLevel::from_score_bps(MEDIUM_TRUST_BPS - 1),
+            TrustLevel::Low
+        );
+        assert_eq!(
+            TrustLevel::from_score_bps(MEDIUM_TRUST_BPS),
+            TrustLevel::Medium
+        );
+        assert_eq!(
+            TrustLevel::from_score_bps(HIGH_TRUST_BPS - 1),
+            TrustLevel::Medium
+        );
+        assert_eq!(TrustLevel::from_score_bps(HIGH_TRUST_BPS), TrustLevel::High);
+
+        // A score justifying Medium may be attested at Medium or below only
+        let justified = TrustLevel::max_justified(6_000, 0);
+        assert!(justified.meets(TrustLevel::Medium));
+        assert!(justified.meets(TrustLevel::Low));
+        assert!(!justified.meets(TrustLevel::High));
+
+        // Below the config floor nothing above Low is justified
+        assert_eq!(TrustLevel::max_justified(9_000, 9_500), TrustLevel::Low);
+        assert_eq!(TrustLevel::max_justified(9_500, 9_500), TrustLevel::High);
+    }
+
+    #[test]
+    fn trust_history_keeps_the_latest_entries_in_order() {
+        let entry = |timestamp: i64| TrustHistoryEntry {
+            level: TrustLevel::Medium.as_u8(),
+            score_bps: 5_000,
+            timestamp,
+        };
+        let timestamps = |history: &TrustHistory| -> Vec<i64> {
+            history
+                .entries_chronological()
+                .iter()
+                .map(|e| e.timestamp)
+                .collect()
+        };
+        let mut history = TrustHistory {
+            agent: Pubkey::new_unique(),
+            entries: [TrustHistoryEntry::default(); TRUST_HISTORY_CAPACITY],
+            head: 0,
+            len: 0,
+            bump: 255,
+        };
+        assert!(history.entries_chronological().is_empty());
+
+        for i in 0..3 {
+            history.push(entry(i));
+        }
+        assert_eq!(timestamps(&history), vec![0, 1, 2]);
+
+        // Past capacity the oldest entries are overwritten
+        for i in 3..20 {
+            history.push(entry(i));
+        }
+        assert_eq!(history.len as usize, TRUST_HISTORY_CAPACITY);
+        assert_eq!(timestamps(&history), (4..20).collect::<Vec<i64>>());
+    }
+
+    /// Encoded size of `value` as an account, discriminator included.
+    fn account_size(value: &impl AnchorSerialize) -> usize {
+        DISCRIMINATOR_LEN + value.try_to_vec().unwrap().len()
+    }
+
+    #[test]
+    fn account_sizes_fit_max_size_values() {
+        // Only strings and options vary in size, so filling those is enough
+        let key = Pubkey::new_unique();
+        let profile = AgentProfile {
+            name: "a".repeat(64),
+            delegate: Some(key),
+            ..zeroed()
+        };
+        assert_eq!(AgentProfile::LEN, account_size(&profile));
+        let legacy = AgentProfileV1 {
+            name: "a".repeat(64),
+            ..zeroed()
+        };
+        assert_eq!(AgentProfileV1::LEN, account_size(&legacy));
+        let commitment = ReasoningCommitment {
+            action_type: "a".repeat(32),
+            reasoning_uri: "a".repeat(256),
+            ..zeroed()
+        };
+        assert_eq!(ReasoningCommitment::LEN, account_size(&commitment));
+        let proof = ReasoningProof {
+            stake: Some(key),
+            ..zeroed()
+        };
+        assert_eq!(ReasoningProof::LEN, account_size(&proof));
+        assert_eq!(StakeAccount::LEN, account_size(&zeroed::<StakeAccount>()));
+        assert_eq!(Treasury::LEN, account_size(&zeroed::<Treasury>()));
+        assert_eq!(Config::LEN, account_size(&zeroed::<Config>()));
+        assert_eq!(TrustHistory::LEN, account_size(&zeroed::<TrustHistory>()));
+    }
+
+    fn attestation(score_bps: u16, trust_level: TrustLevel, attested_at: i64) -> Attestation {
+        Attestation {
+            score_bps,
+            defi_bps: score_bps,
+            prediction_bps: score_bps,
+            trust_level: trust_level.as_u8(),
+            attested_at,
+        }
+    }
+
+    /// Apply `attestation` at time 100 against a fresh history.
+    fn apply(attestation: Attestation, profile: &mut AgentProfile, config: &Config) -> Result<()> {
+        let mut history = zeroed();
+        attestation.apply(Pubkey::new_unique(), profile, &mut history, config, 100)
+    }
+
+    #[test]
+    fn unbacked_high_claims_are_capped_at_medium() {
+        let config = Config {
+            min_stake_for_high: 1_000,
+            ..zeroed()
+        };
+        let mut profile = AgentProfile::new(Pubkey::new_unique(), "agent".into(), 255, 0).unwrap();
+        let mut history: TrustHistory = zeroed();
+        attestation(9_000, TrustLevel::High, 10)
+            .apply(
+                Pubkey::new_unique(),
+                &mut profile,
+                &mut history,
+                &config,
+                100,
+            )
+            .unwrap();
+        assert_eq!(profile.trust_level(), Some(TrustLevel::Medium));
+        assert_eq!((profile.conviction_bps, profile.attested_at), (9_000, 10));
+        assert_eq!(
+            history.entries_chronological(),
+            [TrustHistoryEntry {
+                level: TrustLevel::Medium.as_u8(),
+                score_bps: 9_000,
+                timestamp: 10,
+            }]
+        );
+
+        profile.staked_lamports = config.min_stake_for_high;
+        apply(
+            attestation(9_000, TrustLevel::High, 20),
+            &mut profile,
+            &config,
+        )
+        .unwrap();
+        assert_eq!(profile.trust_level(), Some(TrustLevel::High));
+        assert!(!profile.cap_unbacked_high(&config));
+
+        // Unstaking below the minimum drops the level back to Medium
+        profile.staked_lamports -= 1;
+        assert!(profile.cap_unbacked_high(&config));
+        assert_eq!(profile.trust_level(), Some(TrustLevel::Medium));
+        assert!(!profile.cap_unbacked_high(&config));
+    }
+
+    #[test]
+    fn domain_requirements_map_to_profile_conviction() {
+        let config = Config {
+            min_defi_bps: 7_000,
+            min_prediction_bps: 3_000,
+            ..zeroed()
+        };
+        assert_eq!(config.min_domain_bps(ProofDomain::Defi), 7_000);
+        assert_eq!(config.min_domain_bps(ProofDomain::PredictionMarket), 3_000);
+        assert_eq!(config.min_domain_bps(ProofDomain::Governance), 0);
+        assert_eq!(config.min_domain_bps(ProofDomain::Other), 0);
+
+        let mut profile = AgentProfile::new(Pubkey::new_unique(), "agent".into(), 255, 0).unwrap();
+        let attestation = Attestation {
+            defi_bps: 7_500,
+            prediction_bps: 2_000,
+            ..attestation(6_000, TrustLevel::Medium, 10)
+        };
+        apply(attestation, &mut profile, &config).unwrap();
+        assert_eq!(profile.domain_bps(ProofDomain::Defi), Some(7_500));
+        assert_eq!(
+            profile.domain_bps(ProofDomain::PredictionMarket),
+            Some(2_000)
+        );
+        assert_eq!(profile.domain_bps(ProofDomain::Governance), None);
+        assert_eq!(profile.domain_bps(ProofDomain::Other), None);
+    }
+
+    #[test]
+    fn submissions_must_use_the_next_nonce() {
+        let mut profile = AgentProfile::new(Pubkey::new_unique(), "agent".into(), 255, 0).unwrap();
+        profile.record_proofs(0, 1, 10).unwrap();
+        assert_eq!(profile.nonce, 1);
+
+        for nonce in [0, 2] {
+            assert_eq!(
+                profile.record_proofs(nonce, 1, 20).err(),
+                Some(AxiomError::InvalidNonce.into())
+            );
+        }
+        assert_eq!(profile.nonce, 1);
+
+        profile.record_proofs(1, 1, 20).unwrap();
+        assert_eq!(profile.nonce, 2);
+    }
+
+    #[test]
+    fn submissions_advance_the_proof_counters() {
+        let mut profile = AgentProfile::new(Pubkey::new_unique(), "agent".into(), 255, 0).unwrap();
+        profile.record_proofs(0, 1, 10).unwrap();
+        profile.record_proofs(1, 3, 20).unwrap();
+        assert_eq!((profile.proof_count, profile.last_proof_at), (4, 20));
+
+        assert!(profile.record_proofs(1, 5, 30).is_err());
+        assert_eq!((profile.proof_count, profile.last_proof_at), (4, 20));
+    }
+
+    #[test]
+    fn proofs_are_verifiable_between_the_challenge_window_and_expiry() {
+        let proof = ReasoningProof {
+            verifiable_after: 100,
+            expires_at: 200,
+            ..zeroed()
+        };
+        assert_eq!(
+            proof.check_verifiable(99).err(),
+            Some(AxiomError::ChallengeWindowOpen.into())
+        );
+        assert!(proof.check_verifiable(100).is_ok());
+        assert!(proof.check_verifiable(199).is_ok());
+        assert_eq!(
+            proof.check_verifiable(200).err(),
+            Some(AxiomError::ProofExpired.into())
+        );
+
+        let disputed = ReasoningProof {
+            disputed: true,
+            ..proof
+        };
+        assert_eq!(
+            disputed.check_verifiable(150).err(),
+            Some(AxiomError::ProofDisputed.into())
+        );
+    }
+
+    fn legacy_profile() -> AgentProfileV1 {
+        AgentProfileV1 {
+            authority: Pubkey::new_unique(),
+            name: "legacy-agent".to_string(),
+            total_commitments: 7,
+            total_verified: 5,
+            accountability_score: 9_250,
+            created_at: 1_700_000_000,
+            bump: 254,
+        }
+    }
+
+    /// Raw account data as a legacy profile sits on chain: discriminator,
+    /// borsh body, zero-padded to the fixed legacy size.
+    fn legacy_account_data(legacy: &AgentProfileV1) -> Vec<u8> {
+        let mut data = AgentProfile::DISCRIMINATOR.to_vec();
+        data.extend(legacy.try_to_vec().unwrap());
+        data.resize(AgentProfileV1::LEN, 0);
+        data
+    }
+
+    #[test]
+    fn migrates_v1_profile_to_current_layout() {
+        let legacy = legacy_profile();
+        let data = legacy_account_data(&legacy);
+        assert_eq!(data.len(), 135);
+
+        let profile = AgentProfileV1::from_account_data(&data).unwrap().upgrade();
+        assert_eq!(profile.version, AGENT_PROFILE_VERSION);
+        assert_eq!(profile.authority, legacy.authority);
+        assert_eq!(profile.seed_authority, legacy.authority);
+        assert_eq!(profile.name, legacy.name);
+        assert_eq!(profile.total_commitments, 7);
+        assert_eq!(profile.total_verified, 5);
+        assert_eq!(profile.accountability_score, 9_250);
+        assert_eq!(profile.created_at, 1_700_000_000);
+        assert_eq!(profile.bump, 254);
+        assert_eq!(profile.trust_level(), Some(TrustLevel::Unknown));
+        assert_eq!(profile.nonce, 0);
+        assert_eq!(profile.delegate, None);
+
+        // The rewritten account decodes as a current profile, version first
+        let mut migrated = vec![0u8; AgentProfile::LEN];
+        profile.try_serialize(&mut &mut migrated[..]).unwrap();
+        assert_eq!(migrated[DISCRIMINATOR_LEN], AGENT_PROFILE_VERSION);
+        let decoded = AgentProfile::try_deserialize(&mut &migrated[..]).unwrap();
+        assert_eq!(decoded.name, legacy.name);
+        assert_eq!(decoded.authority, legacy.authority);
+    }
+
+    #[test]
+    fn rejects_already_migrated_and_foreign_data() {
+        let profile = legacy_profile().upgrade();
+        let mut current = vec![0u8; AgentProfile::LEN];
+        profile.try_serialize(&mut &mut current[..]).unwrap();
+        assert_eq!(
+            AgentProfileV1::from_account_data(&current).err(),
+            Some(AxiomError::AlreadyMigrated.into())
+        );
+
+        let mut foreign = legacy_account_data(&legacy_profile());
+        foreign[..DISCRIMINATOR_LEN].copy_from_slice(Config::DISCRIMINATOR);
+        assert_eq!(
+            AgentProfileV1::from_account_data(&foreign).err(),
+            Some(AxiomError::AccountVersionMismatch.into())
+        );
+    }
+
+    #[test]
+    fn error_codes_are_stable() {
+        // Clients match on these numbers; variants may only be appended
+        let expected = [
+            (AxiomError::NameTooLong, 6000),
+            (AxiomError::NameEmpty, 6001),
+            (AxiomError::ActionTypeTooLong, 6002),
+            (AxiomError::InvalidConfidence, 6003),
+            (AxiomError::UriTooLong, 6004),
+            (AxiomError::UriEmpty, 6005),
+            (AxiomError::AlreadyRevealed, 6006),
+            (AxiomError::AgentMismatch, 6007),
+            (AxiomError::Overflow, 6008),
+            (AxiomError::ProofHashEmpty, 6009),
+            (AxiomError::AgentAlreadyRegistered, 6010),
+            (AxiomError::Unauthorized, 6011),
+            (AxiomError::ProofExpired, 6012),
+            (AxiomError::InvalidTrustLevel, 6013),
+            (AxiomError::InvalidBasisPoints, 6014),
+            (AxiomError::ProgramPaused, 6015),
+            (AxiomError::InvalidTtl, 6016),
+            (AxiomError::InvalidStakeAmount, 6017),
+            (AxiomError::StakeLocked, 6018),
+            (AxiomError::ProofHasStake, 6019),
+            (AxiomError::InvalidBatchSize, 6020),
+            (AxiomError::BatchAccountMismatch, 6021),
+            (AxiomError::DuplicateProofHash, 6022),
+            (AxiomError::AlreadyMigrated, 6023),
+            (AxiomError::AccountVersionMismatch, 6024),
+            (AxiomError::InvalidNonce, 6025),
+            (AxiomError::InsufficientTrust, 6026),
+            (AxiomError::NotAuthorityOrDelegate, 6027),
+            (AxiomError::InsufficientTreasuryFunds, 6028),
+            (AxiomError::InvalidDomain, 6029),
+            (AxiomError::TrustLevelNotJustified, 6030),
+            (AxiomError::StaleAttestation, 6031),
+            (AxiomError::AttestationInFuture, 6032),
+            (AxiomError::ChallengeWindowOpen, 6033),
+            (AxiomError::ProofDisputed, 6034),
+            (AxiomError::ProofAlreadyVerified, 6035),
+            (AxiomError::InvalidChallengeWindow, 6036),
+            (AxiomError::InsufficientDomainConviction, 6037),
+            (AxiomError::InvalidStakeLock, 6038),
+            (AxiomError::InvalidNewOwner, 6039),
+        ];
+        for (error, code) in expected {
+            assert_eq!(u32::from(error), code, "{error:?}");
+        }
+    }
 }
@@ -0,0 +1,183 @@
+//! Off-chain account readers (enabled with the `client` feature).
+//!
+//! The crate does not depend on an RPC client; callers fetch raw account
+//! data at the address from [`crate::instructions`] with whatever client
+//! they already use and hand it to these decoders, which apply Anchor's
+//! discriminator check.
+
+use anchor_lang::prelude::*;
+use anchor_lang::AccountDeserialize;
+
+use crate::{
+    AgentProfile, ProofDomain, ReasoningProof, TrustHistory, TrustHistoryEntry, DISCRIMINATOR_LEN,
+};
+
+/// Decode a fetched agent profile account.
+///
+/// `None` (no account at the PDA) means the agent is unregistered and is
+/// returned as `Ok(None)` rather than an error.
+///
+/// ```ignore
+/// let address = axiom::instructions::agent_profile_address(&wallet);
+/// let data = rpc.get_account(&address).await.ok().map(|a| a.data);
+/// let profile = axiom::reader::decode_agent_profile(data.as_deref())?;
+/// let level = profile.and_then(|p| p.trust_level());
+/// ```
+pub fn decode_agent_profile(data: Option<&[u8]>) -> Result<Option<AgentProfile>> {
+    match data {
+        None => Ok(None),
+        Some(mut data) => AgentProfile::try_deserialize(&mut data).map(Some),
+    }
+}
+
+/// Decode a fetched trust history account into its entries, oldest first.
+///
+/// A missing account (history never initialized) yields no entries.
+pub fn decode_trust_history(data: Option<&[u8]>) -> Result<Vec<TrustHistoryEntry>> {
+    match data {
+        None => Ok(Vec::new()),
+        Some(mut data) => {
+            TrustHistory::try_deserialize(&mut data).map(|history| history.entries_chronological())
+        }
+    }
+}
+
+/// Byte offset of `ReasoningProof::agent` within the account data.
+pub const PROOF_AGENT_OFFSET: usize = DISCRIMINATOR_LEN;
+
+/// Byte offset of `ReasoningProof::domain` within the account data
+/// (after the discriminator, `agent` and `proof_hash`).
+pub const PROOF_DOMAIN_OFFSET: usize = DISCRIMINATOR_LEN + 32 + 32;
+
+/// `(offset, bytes)` memcmp filters selecting an agent's proofs in one
+/// domain, for `getProgramAccounts`. The first filter matches the
+/// `ReasoningProof` discriminator.
+///
+/// ```ignore
+/// let filters = axiom::reader::proof_memcmp_filters(&agent_profile, ProofDomain::Defi)
+///     .into_iter()
+///     .map(|(offset, bytes)| RpcFilterType::Memcmp(Memcmp::new_raw_bytes(offset, bytes)))
+///     .collect();
+/// let config = RpcProgramAccountsConfig { filters: Some(filters), ..Default::default() };
+/// let accounts = rpc.get_program_accounts_with_config(&axiom::ID, config).await?;
+/// let latest = axiom::reader::latest_verified_proof(
+///     accounts.iter().map(|(_, a)| a.data.as_slice()),
+///     &agent_profile,
+///     ProofDomain::Defi,
+/// )?;
+/// ```
+pub fn proof_memcmp_filters(agent_profile: &Pubkey, domain: ProofDomain) -> Vec<(usize, Vec<u8>)> {
+    vec![
+        (0, ReasoningProof::DISCRIMINATOR.to_vec()),
+        (PROOF_AGENT_OFFSET, agent_profile.to_bytes().to_vec()),
+        (PROOF_DOMAIN_OFFSET, vec![domain.as_u8()]),
+    ]
+}
+
+/// The most recently created verified proof of `agent_profile` in
+/// `domain` among fetched proof accounts.
+///
+/// Accounts for other agents or domains are ignored, so results of an
+/// unfiltered query are handled correctly too; undecodable data is an
+/// error.
+pub fn latest_verified_proof<'a>(
+    accounts: impl IntoIterator<Item = &'a [u8]>,
+    agent_profile: &Pubkey,
+    domain: ProofDomain,
+) -> Result<Option<ReasoningProof>> {
+    let mut latest: Option<ReasoningProof> = None;
+    for mut data in accounts {
+        let proof = ReasoningProof::try_deserialize(&mut data)?;
+        if proof.agent != *agent_profile || proof.proof_domain() != Some(domain) || !proof.verified
+        {
+            continue;
+        }
+        if latest
+            .as_ref()
+            .is_none_or(|l| proof.created_at > l.created_at)
+        {
+            latest = Some(proof);
+        }
+    }
+    Ok(latest)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn proof(agent: Pubkey, domain: ProofDomain, created_at: i64, verified: bool) -> Vec<u8> {
+        let proof = ReasoningProof {
+            agent,
+            proof_hash: [created_at as u8; 32],
+            domain: domain.as_u8(),
+            created_at,
+            verified,
+            expires_at: i64::MAX,
+            verifiable_after: 0,
+            disputed: false,
+            stake: None,
+            bump: 255,
+        };
+        let mut data = Vec::with_capacity(ReasoningProof::LEN);
+        proof.try_serialize(&mut data).unwrap();
+        data
+    }
+
+    #[test]
+    fn agent_profiles_round_trip_domain_conviction() {
+        let mut profile = AgentProfile::new(Pubkey::new_unique(), "agent".into(), 255, 0).unwrap();
+        profile.last_defi_bps = 7_500;
+        profile.last_prediction_bps = 2_000;
+        let mut data = Vec::with_capacity(AgentProfile::LEN);
+        profile.try_serialize(&mut data).unwrap();
+        data.resize(AgentProfile::LEN, 0);
+
+        let decoded = decode_agent_profile(Some(&data)).unwrap().unwrap();
+        assert_eq!(decoded.domain_bps(ProofDomain::Defi), Some(7_500));
+        assert_eq!(
+            decoded.domain_bps(ProofDomain::PredictionMarket),
+            Some(2_000)
+        );
+        assert!(decode_agent_profile(None).unwrap().is_none());
+    }
+
+    #[test]
+    fn memcmp_offsets_match_serialized_proofs() {
+        let agent = Pubkey::new_unique();
+        let data = proof(agent, ProofDomain::Governance, 1, true);
+        for (offset, bytes) in proof_memcmp_filters(&agent, ProofDomain::Governance) {
+            assert_eq!(&data[offset..offset + bytes.len()], bytes.as_slice());
+        }
+    }
+
+    #[test]
+    fn latest_verified_proof_skips_non_matching_accounts() {
+        let agent = Pubkey::new_unique();
+        let accounts = [
+            proof(agent, ProofDomain::Defi, 10, true),
+            proof(agent, ProofDomain::Defi, 30, true),
+            proof(agent, ProofDomain::Defi, 20, true),
+            proof(agent, ProofDomain::Defi, 40, false),
+            proof(agent, ProofDomain::Governance, 50, true),
+            proof(Pubkey::new_unique(), ProofDomain::Defi, 60, true),
+        ];
+
+        let latest = latest_verified_proof(
+            accounts.iter().map(Vec::as_slice),
+            &agent,
+            ProofDomain::Defi,
+        )
+        .unwrap()
+        .unwrap();
+        assert_eq!(latest.created_at, 30);
+
+        let none = latest_verified_proof(
+            accounts.iter().map(Vec::as_slice),
+            &agent,
+            ProofDomain::Other,
+        )
+        .unwrap();
+        assert!(none.is_none());
+    }
+}
@@ -41,6 +41,8 @@ const DEFAULT_RPC = "https://api.devnet.solana.com";
 /** PDA seed prefixes (must match the Anchor program) */
 const SEED_AGENT = Buffer.from("agent");
 const SEED_COMMITMENT = Buffer.from("commitment");
+const SEED_CONFIG = Buffer.from("config");
+const SEED_TRUST_HISTORY = Buffer.from("trust_history");
 
 // ─── IDL (embedded for zero-dependency usage) ─────────────────────────────
 
@@ -52,8 +54,15 @@ const DISCRIMINATORS = {
   registerAgent: Buffer.from([135, 157, 66, 195, 2, 113, 175, 30]),
   commitReasoning: Buffer.from([163, 80, 25, 135, 94, 49, 218, 44]),
   revealReasoning: Buffer.from([76, 215, 6, 241, 209, 207, 84, 96]),
+  initializeTrustHistory: Buffer.from([197, 11, 128, 18, 220, 223, 92, 74]),
 } as const;
 
+/**
+ * Size of an AgentProfile written before the layout gained its leading
+ * version byte (see migrate_agent_profile in the program).
+ */
+const LEGACY_AGENT_PROFILE_LEN = 135;
+
 // Account discriminators for deserialization
 const ACCOUNT_DISCRIMINATORS = {
   AgentProfile: Buffer.from([60, 227, 42, 24, 0, 87, 86, 205]),
@@ -75,6 +84,28 @@ export function deriveAgentPDA(
   );
 }
 
+/**
+ * Derive the singleton program config PDA.
+ */
+export function deriveConfigPDA(
+  programId: PublicKey = SOLPRISM_PROGRAM_ID
+): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync([SEED_CONFIG], programId);
+}
+
+/**
+ * Derive the trust history PDA for a given agent profile.
+ */
+export function deriveTrustHistoryPDA(
+  agentProfile: PublicKey,
+  programId: PublicKey = SOLPRISM_PROGRAM_ID
+): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [SEED_TRUST_HISTORY, agentProfile.toBuffer()],
+    programId
+  );
+}
+
 /**
  * Derive the commitment PDA for a given agent profile and nonce.
  */
@@ -125,6 +156,8 @@ export function buildRegisterAgentIx(
   programId: PublicKey = SOLPRISM_PROGRAM_ID
 ): TransactionInstruction {
   const [agentProfile] = deriveAgentPDA(authority, programId);
+  const [trustHistory] = deriveTrustHistoryPDA(agentProfile, programId);
+  const [config] = deriveConfigPDA(programId);
 
   const data = Buffer.concat([
     DISCRIMINATORS.registerAgent,
@@ -134,7 +167,9 @@ export function buildRegisterAgentIx(
   return new TransactionInstruction({
     keys: [
       { pubkey: agentProfile, isSigner: false, isWritable: true },
+      { pubkey: trustHistory, isSigner: false, isWritable: true },
       { pubkey: authority, isSigner: true, isWritable: true },
+      { pubkey: config, isSigner: false, isWritable: false },
       { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
     ],
     programId,
@@ -142,6 +177,30 @@ export function buildRegisterAgentIx(
   });
 }
 
+/**
+ * Build an initialize_trust_history instruction, for agents registered
+ * before register_agent created their trust history. Any payer may sign.
+ */
+export function buildInitializeTrustHistoryIx(
+  payer: PublicKey,
+  agentAuthority: PublicKey,
+  programId: PublicKey = SOLPRISM_PROGRAM_ID
+): TransactionInstruction {
+  const [agentProfile] = deriveAgentPDA(agentAuthority, programId);
+  const [trustHistory] = deriveTrustHistoryPDA(agentProfile, programId);
+
+  return new TransactionInstruction({
+    keys: [
+      { pubkey: trustHistory, isSigner: false, isWritable: true },
+      { pubkey: agentProfile, isSigner: false, isWritable: false },
+      { pubkey: payer, isSigner: true, isWritable: true },
+      { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+    ],
+    programId,
+    data: DISCRIMINATORS.initializeTrustHistory,
+  });
+}
+
 /**
  * Build a commit_reasoning instruction.
  */
@@ -215,8 +274,8 @@ function readString(buf: Buffer, offset: number): [string, number] {
  * Deserialize an AgentProfile account.
  */
 export function deserializeAgentProfile(data: Buffer): OnChainAgentProfile {
-  // Skip 8-byte discriminator
-  let offset = 8;
+  // Skip 8-byte discriminator, plus the version byte on migrated layouts
+  let offset = data.length === LEGACY_AGENT_PROFILE_LEN ? 8 : 9;
 
   // authority: Pubkey (32 bytes)
   const authority = new PublicKey(data.slice(offset, offset + 32)).toBase58();
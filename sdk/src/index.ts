@@ -69,7 +69,10 @@ export {
   SOLPRISM_PROGRAM_ID,
   deriveAgentPDA,
   deriveCommitmentPDA,
+  deriveConfigPDA,
+  deriveTrustHistoryPDA,
   buildRegisterAgentIx,
+  buildInitializeTrustHistoryIx,
   buildCommitReasoningIx,
   buildRevealReasoningIx,
   deserializeAgentProfile,